@@ -0,0 +1,331 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::units::{Distance, TimeSpan};
+
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Gaps between track points longer than this with less movement than
+/// `PAUSE_DISTANCE` are treated as pauses and not counted as time.
+const PAUSE_GAP: f64 = 60.0;
+const PAUSE_DISTANCE: f64 = 10.0;
+
+struct TrackPoint {
+    position: Option<(f64, f64)>,
+    time: Option<f64>,
+    distance: Option<f64>,
+}
+
+/// A recorded activity as cumulative distance in meters and moving time
+/// in seconds at every track point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Activity {
+    points: Vec<(f64, f64)>,
+}
+
+/// Parses an ISO 8601 timestamp like `2024-05-01T07:30:00.5+02:00` into
+/// seconds since the Unix epoch. Timestamps without offset are UTC.
+fn parse_timestamp(s: &str) -> Option<f64> {
+    let s = s.trim();
+    let (date, time) = s.split_once('T')?;
+    let mut date_parts = date.split('-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (
+        date_parts.next()??,
+        date_parts.next()??,
+        date_parts.next()??,
+    );
+
+    let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+        (time, 0.0)
+    } else if let Some(index) = time.rfind(['+', '-']) {
+        let (time, offset) = time.split_at(index);
+        let sign = if offset.starts_with('-') { -1.0 } else { 1.0 };
+        let (hours, minutes) = match offset[1..].split_once(':') {
+            Some(parts) => parts,
+            None => (offset.get(1..3)?, offset.get(3..)?),
+        };
+        let offset =
+            hours.parse::<f64>().ok()? * 3600.0 + minutes.parse::<f64>().unwrap_or(0.0) * 60.0;
+        (time, sign * offset)
+    } else {
+        (time, 0.0)
+    };
+    let mut time_parts = time.split(':');
+    let hours = time_parts.next()?.parse::<f64>().ok()?;
+    let minutes = time_parts.next()?.parse::<f64>().ok()?;
+    let seconds = time_parts.next()?.parse::<f64>().ok()?;
+
+    // Days since the epoch for a date of the proleptic Gregorian calendar.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(days as f64 * 86_400.0 + hours * 3600.0 + minutes * 60.0 + seconds - offset)
+}
+
+fn haversine(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().asin()
+}
+
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.descendants()
+        .find(|child| child.tag_name().name() == name)
+        .and_then(|child| child.text())
+}
+
+/// Reads the track segments of a GPX or TCX document. In TCX files every
+/// `Track` is a segment, in GPX files every `trkseg`.
+fn segments(document: &roxmltree::Document) -> Vec<Vec<TrackPoint>> {
+    let root = document.root_element();
+    match root.tag_name().name() {
+        "gpx" => root
+            .descendants()
+            .filter(|node| node.tag_name().name() == "trkseg")
+            .map(|segment| {
+                segment
+                    .children()
+                    .filter(|node| node.tag_name().name() == "trkpt")
+                    .map(|point| TrackPoint {
+                        position: point
+                            .attribute("lat")
+                            .and_then(|lat| lat.parse().ok())
+                            .zip(point.attribute("lon").and_then(|lon| lon.parse().ok())),
+                        time: child_text(point, "time").and_then(parse_timestamp),
+                        distance: None,
+                    })
+                    .collect()
+            })
+            .collect(),
+        _ => root
+            .descendants()
+            .filter(|node| node.tag_name().name() == "Track")
+            .map(|track| {
+                track
+                    .children()
+                    .filter(|node| node.tag_name().name() == "Trackpoint")
+                    .map(|point| TrackPoint {
+                        position: child_text(point, "LatitudeDegrees")
+                            .and_then(|lat| lat.parse().ok())
+                            .zip(
+                                child_text(point, "LongitudeDegrees")
+                                    .and_then(|lon| lon.parse().ok()),
+                            ),
+                        time: child_text(point, "Time").and_then(parse_timestamp),
+                        distance: child_text(point, "DistanceMeters")
+                            .and_then(|distance| distance.parse().ok()),
+                    })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/// Fills in missing timestamps by interpolating between the neighbouring
+/// points by distance and drops points before the first and after the
+/// last timestamp. Returns the distance and time of every point.
+fn interpolate_times(points: Vec<(f64, Option<f64>)>) -> Vec<(f64, f64)> {
+    let timed: Vec<usize> = (0..points.len())
+        .filter(|&i| points[i].1.is_some())
+        .collect();
+    let mut result = Vec::new();
+    for pair in timed.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let (start_distance, start_time) = (points[start].0, points[start].1.unwrap_or_default());
+        let (end_distance, end_time) = (points[end].0, points[end].1.unwrap_or_default());
+        for &(distance, _) in &points[start..end] {
+            let fraction = if end_distance > start_distance {
+                (distance - start_distance) / (end_distance - start_distance)
+            } else {
+                0.0
+            };
+            result.push((distance, start_time + fraction * (end_time - start_time)));
+        }
+    }
+    if let Some(&last) = timed.last() {
+        result.push((points[last].0, points[last].1.unwrap_or_default()));
+    }
+    result
+}
+
+/// Parses a GPX or TCX file into an activity. Points without timestamp are
+/// interpolated, gaps between segments and long stops are not counted.
+pub fn parse_activity(xml: &str) -> Result<Activity, String> {
+    let document =
+        roxmltree::Document::parse(xml).map_err(|err| format!("Invalid GPX/TCX file: {}", err))?;
+    let segments = segments(&document);
+    let use_recorded_distance = segments
+        .iter()
+        .flatten()
+        .all(|point| point.distance.is_some());
+
+    let mut points: Vec<(f64, f64)> = Vec::new();
+    let mut distance = 0.0;
+    let mut moving_time = 0.0;
+    for segment in segments {
+        let mut previous_position = None;
+        let with_distance: Vec<(f64, Option<f64>)> = segment
+            .into_iter()
+            .map(|point| {
+                if use_recorded_distance {
+                    distance = point.distance.unwrap_or(distance);
+                } else if let Some(position) = point.position {
+                    if let Some(previous) = previous_position {
+                        distance += haversine(previous, position);
+                    }
+                    previous_position = Some(position);
+                }
+                (distance, point.time)
+            })
+            .collect();
+        let mut previous: Option<(f64, f64)> = None;
+        for (distance, time) in interpolate_times(with_distance) {
+            if let Some((previous_distance, previous_time)) = previous {
+                let gap = time - previous_time;
+                if gap > 0.0 && !(gap > PAUSE_GAP && distance - previous_distance < PAUSE_DISTANCE)
+                {
+                    moving_time += gap;
+                }
+            }
+            points.push((distance, moving_time));
+            previous = Some((distance, time));
+        }
+    }
+
+    if points.len() < 2 {
+        return Err("The file contains no track points with timestamps".to_string());
+    }
+    Ok(Activity { points })
+}
+
+impl Activity {
+    /// Total distance, rounded to whole meters.
+    pub fn distance(&self) -> Distance {
+        Distance::meters(self.points.last().map_or(0.0, |point| point.0).round() as usize)
+    }
+
+    /// Moving time when `distance` was reached, interpolated between track
+    /// points.
+    pub fn time_at(&self, distance: Distance) -> Option<TimeSpan> {
+        let distance = distance.as_meters() as f64;
+        let index = self.points.iter().position(|point| point.0 >= distance)?;
+        let (end_distance, end_time) = self.points[index];
+        let seconds = match index.checked_sub(1).map(|i| self.points[i]) {
+            Some((start_distance, start_time)) if end_distance > start_distance => {
+                start_time
+                    + (distance - start_distance) / (end_distance - start_distance)
+                        * (end_time - start_time)
+            }
+            _ => end_time,
+        };
+        Some(TimeSpan::from_secs_f64(seconds.max(0.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEW_YEAR: f64 = 1_704_103_200.0;
+
+    fn gpx(points: &[(f64, Option<&str>)]) -> String {
+        let points: String = points
+            .iter()
+            .map(|(lat, time)| match time {
+                Some(time) => format!(
+                    r#"<trkpt lat="{}" lon="0"><time>2024-01-01T{}Z</time></trkpt>"#,
+                    lat, time
+                ),
+                None => format!(r#"<trkpt lat="{}" lon="0"></trkpt>"#, lat),
+            })
+            .collect();
+        format!(r#"<gpx><trk><trkseg>{}</trkseg></trk></gpx>"#, points)
+    }
+
+    #[test]
+    fn parses_timestamps_with_offsets() {
+        assert_eq!(parse_timestamp("2024-01-01T10:00:00Z"), Some(NEW_YEAR));
+        assert_eq!(
+            parse_timestamp("2024-01-01T10:00:00.5Z"),
+            Some(NEW_YEAR + 0.5)
+        );
+        assert_eq!(parse_timestamp("2024-01-01T12:00:00+02:00"), Some(NEW_YEAR));
+        assert_eq!(parse_timestamp("2024-01-01T12:00:00+0200"), Some(NEW_YEAR));
+        assert_eq!(parse_timestamp("2024-01-01T09:30:00-00:30"), Some(NEW_YEAR));
+        assert_eq!(parse_timestamp("2024-01-01T10:00:00"), Some(NEW_YEAR));
+        assert_eq!(parse_timestamp("2024-01-01T10:00:00+0"), None);
+        assert_eq!(parse_timestamp("2024-01-01"), None);
+    }
+
+    #[test]
+    fn interpolates_missing_timestamps() {
+        let activity = parse_activity(&gpx(&[
+            (0.0, Some("10:00:00")),
+            (0.001, None),
+            (0.002, Some("10:01:00")),
+        ]))
+        .unwrap();
+        assert_eq!(activity.distance(), Distance::meters(222));
+        let half = activity.time_at(Distance::meters(111)).unwrap();
+        assert!((half.as_duration().as_secs_f64() - 30.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn leaves_out_pauses() {
+        let activity = parse_activity(&gpx(&[
+            (0.0, Some("10:00:00")),
+            (0.001, Some("10:01:00")),
+            (0.001, Some("10:05:00")),
+            (0.002, Some("10:06:00")),
+        ]))
+        .unwrap();
+        let total = activity.time_at(activity.distance()).unwrap();
+        assert!((total.as_duration().as_secs_f64() - 120.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn uses_recorded_tcx_distance() {
+        let tcx = r#"<TrainingCenterDatabase><Activities><Activity><Lap><Track>
+            <Trackpoint><Time>2024-01-01T10:00:00Z</Time><DistanceMeters>0</DistanceMeters></Trackpoint>
+            <Trackpoint><Time>2024-01-01T10:05:00Z</Time><DistanceMeters>1000</DistanceMeters></Trackpoint>
+            </Track></Lap></Activity></Activities></TrainingCenterDatabase>"#;
+        let activity = parse_activity(tcx).unwrap();
+        assert_eq!(activity.distance(), Distance::meters(1000));
+        assert_eq!(
+            activity.time_at(Distance::meters(500)),
+            Some(TimeSpan::from_secs_f64(150.0))
+        );
+    }
+
+    #[test]
+    fn rejects_files_without_timestamps() {
+        assert!(parse_activity(&gpx(&[(0.0, None), (0.001, None)])).is_err());
+        assert!(parse_activity("<gpx>").is_err());
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::units::{TimeOfDay, TimeSpan};
+use std::time::Duration;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Formats the time of day reached `elapsed` after `start_time` as
+/// `hh:mm:ss`. Times past midnight get a `(+n)` day suffix.
+pub fn format_clock_time(start_time: TimeOfDay, elapsed: TimeSpan) -> String {
+    let total_seconds = start_time
+        .as_duration()
+        .as_secs()
+        .saturating_add(elapsed.as_secs());
+    let days = total_seconds / SECONDS_PER_DAY;
+    let time_of_day =
+        TimeOfDay::since_midnight(Duration::from_secs(total_seconds % SECONDS_PER_DAY));
+    if days > 0 {
+        format!("{} (+{})", time_of_day, days)
+    } else {
+        time_of_day.to_string()
+    }
+}
+
+/// Parses a time given as `h:mm` or `h:mm:ss`. Hours past the day are
+/// only accepted with `allow_over_24h`, as for times since a start.
+pub fn parse_hours_minutes(s: &str, allow_over_24h: bool) -> Result<Duration, String> {
+    let parts: Vec<&str> = s.trim().split(':').collect();
+    if parts.len() != 2 && parts.len() != 3 {
+        return Err("Invalid format".to_string());
+    }
+    let hours: u64 = parts[0].parse().map_err(|_| "Invalid hours".to_string())?;
+    let minutes: u64 = parts[1]
+        .parse()
+        .map_err(|_| "Invalid minutes".to_string())?;
+    let seconds: u64 = match parts.get(2) {
+        Some(part) => part.parse().map_err(|_| "Invalid seconds".to_string())?,
+        None => 0,
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return Err("Minutes and seconds must be less than 60".to_string());
+    }
+    if hours >= 24 && !allow_over_24h {
+        return Err("Not a valid time of day".to_string());
+    }
+    hours
+        .checked_mul(3600)
+        .and_then(|hours| hours.checked_add(minutes * 60 + seconds))
+        .map(Duration::from_secs)
+        .ok_or_else(|| "Hours are too large".to_string())
+}
+
+/// Parses a time of day given as `hh:mm` or `hh:mm:ss`.
+pub fn parse_time_of_day(s: &str) -> Result<TimeOfDay, String> {
+    parse_hours_minutes(s, false).map(TimeOfDay::since_midnight)
+}
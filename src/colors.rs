@@ -30,3 +30,5 @@ pub const BLUE4: &str = "#1999e8";
 pub const BLUE5: &str = "#1b242c";
 pub const GREY: &str = "#ced2d6";
 pub const WHITE: &str = "#ffffff";
+pub const ORANGE: &str = "#f0ad4e";
+pub const RED: &str = "#d9534f";
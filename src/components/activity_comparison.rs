@@ -0,0 +1,185 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::activity::{parse_activity, Activity};
+use crate::colors;
+use crate::components::ErrorMessage;
+use crate::files::{read_text, take_selected_file};
+use crate::form_state::FormState;
+use crate::splits::split_times;
+use crate::units::TimeSpan;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+fn format_difference(actual: TimeSpan, planned: TimeSpan) -> String {
+    if actual >= planned {
+        format!("+{}", actual - planned)
+    } else {
+        format!("-{}", planned - actual)
+    }
+}
+
+#[component]
+pub fn ActivityComparison(
+    forms_get: ReadSignal<Vec<usize>>,
+    form_states_get: ReadSignal<HashMap<usize, FormState>>,
+) -> impl IntoView {
+    let (activity_get, activity_set) = signal(None::<Activity>);
+    let (selected_get, selected_set) = signal(None::<usize>);
+    let (error_message_get, error_message_set) = signal(String::new());
+
+    let handle_file = move |ev: web_sys::Event| {
+        if let Some(file) = take_selected_file(&ev) {
+            leptos::task::spawn_local(async move {
+                match read_text(file).await.and_then(|xml| parse_activity(&xml)) {
+                    Ok(activity) => {
+                        activity_set.set(Some(activity));
+                        error_message_set.set(String::new());
+                    }
+                    Err(err) => error_message_set.set(err),
+                }
+            });
+        }
+    };
+
+    // Falls back to the first form when none is selected or the selected
+    // one was deleted.
+    let selected_form = move || {
+        let forms = forms_get.get();
+        selected_get
+            .get()
+            .filter(|id| forms.contains(id))
+            .or_else(|| forms.first().copied())
+    };
+
+    view! {
+        <details class="mt-4">
+            <summary class="cursor-pointer">"Compare activity"</summary>
+            <div
+                class="border border-solid border-gray-300 p-4 mt-2 rounded-md text-white flex flex-col gap-3"
+                style=format!("background-color: {}", colors::BLUE1)
+            >
+                <div class="flex flex-wrap gap-5 items-center">
+                    <label>
+                        "GPX/TCX file: "
+                        <input type="file" accept=".gpx,.tcx" on:change=handle_file />
+                    </label>
+                    <label>
+                        "Plan: "
+                        <select
+                            style=format!(
+                                "color: {}; background-color: {}; border-radius: 3px;",
+                                colors::WHITE,
+                                colors::BLUE4,
+                            )
+                            on:change=move |ev| {
+                                selected_set.set(event_target_value(&ev).parse::<usize>().ok());
+                            }
+                        >
+                            {move || {
+                                let selected = selected_form();
+                                form_states_get
+                                    .with(|states| {
+                                        forms_get
+                                            .get()
+                                            .into_iter()
+                                            .enumerate()
+                                            .map(|(index, id)| {
+                                                let label = states
+                                                    .get(&id)
+                                                    .map(|state| state.label.clone())
+                                                    .filter(|label| !label.is_empty())
+                                                    .unwrap_or_else(|| format!("Form {}", index + 1));
+                                                view! {
+                                                    <option value=id.to_string() selected=selected == Some(id)>
+                                                        {label}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()
+                                    })
+                            }}
+                        </select>
+                    </label>
+                    <div>
+                        {move || {
+                            activity_get
+                                .with(|activity| {
+                                    activity
+                                        .as_ref()
+                                        .map(|activity| format!("{} recorded", activity.distance()))
+                                        .unwrap_or_default()
+                                })
+                        }}
+                    </div>
+                </div>
+                <ErrorMessage error_message_get=error_message_get />
+                <div class="text-sm">
+                    "Times are moving times: planned stops and pauses in the activity are left out."
+                </div>
+                <table class="text-left">
+                    <thead>
+                        <tr>
+                            <th class="pr-4">"Distance"</th>
+                            <th class="pr-4">"Planned moving"</th>
+                            <th class="pr-4">"Actual moving"</th>
+                            <th class="pr-4">"Difference"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let state = selected_form()
+                                .and_then(|id| form_states_get.with(|states| states.get(&id).cloned()))
+                                .unwrap_or_default();
+                            activity_get
+                                .with(|activity| {
+                                    let Some(activity) = activity else {
+                                        return Vec::new();
+                                    };
+                                    split_times(state.pace, state.distance, state.splits)
+                                        .into_iter()
+                                        .map(|(distance, planned)| {
+                                            let (actual, difference) = match activity.time_at(distance) {
+                                                Some(actual) => {
+                                                    (actual.to_string(), format_difference(actual, planned))
+                                                }
+                                                None => ("—".to_string(), "—".to_string()),
+                                            };
+                                            view! {
+                                                <tr>
+                                                    <td class="pr-4">{distance.to_string()}</td>
+                                                    <td class="pr-4">{planned.to_string()}</td>
+                                                    <td class="pr-4">{actual}</td>
+                                                    <td class="pr-4">{difference}</td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()
+                                })
+                        }}
+                    </tbody>
+                </table>
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::clock_time::format_clock_time;
+use crate::colors;
+use crate::components::FieldError;
+use crate::course::{format_course, parse_course, Checkpoint, CutoffStatus};
+use crate::form_state::FormState;
+use crate::input_text::InputText;
+use crate::stops::Stop;
+use crate::units::{Pace, TimeOfDay, TimeSpan};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+fn format_margin(margin: i64) -> String {
+    let sign = if margin < 0 { "-" } else { "+" };
+    format!("{}{}", sign, TimeSpan::from_secs(margin.unsigned_abs()))
+}
+
+#[component]
+pub fn CourseCheckpoints(
+    id: usize,
+    pace_get: ReadSignal<Pace>,
+    start_time_get: ReadSignal<Option<TimeOfDay>>,
+    stops_get: ReadSignal<Vec<Stop>>,
+    course_get: ReadSignal<Vec<Checkpoint>>,
+    course_set: WriteSignal<Vec<Checkpoint>>,
+    error_message_get: ReadSignal<String>,
+    error_message_set: WriteSignal<String>,
+    set_form_states: WriteSignal<HashMap<usize, FormState>>,
+) -> impl IntoView {
+    let text = InputText::new(course_get, |course| format_course(course));
+
+    let handle_input = move |ev| {
+        let course_str = event_target_value(&ev);
+        let valid = match parse_course(&course_str) {
+            Ok(checkpoints) => {
+                course_set.set(checkpoints);
+                error_message_set.set(String::new());
+                true
+            }
+            Err(err) => {
+                error_message_set.set(format!("Course error: {}", err));
+                false
+            }
+        };
+        text.input(course_str, valid);
+        set_form_states.update(|states| {
+            if let Some(state) = states.get_mut(&id) {
+                state.course = course_get.get();
+            }
+        });
+    };
+
+    let error_id = format!("form-{}-course-error", id);
+
+    view! {
+        <details class="mt-2">
+            <summary class="cursor-pointer">"Course checkpoints"</summary>
+            <textarea
+                rows="4"
+                placeholder="Name, distance (m), cutoff (h:mm or @hh:mm)"
+                class="w-full mt-2 px-2 py-1 rounded"
+                style=format!("color: {}; background-color: {};", colors::WHITE, colors::BLUE4)
+                prop:value=move || text.get()
+                aria-invalid=move || (!error_message_get.get().is_empty()).to_string()
+                aria-describedby=error_id.clone()
+                on:input=handle_input
+                on:focus=move |_| text.focus()
+                on:blur=move |_| text.blur()
+            ></textarea>
+            <FieldError id=error_id error_message_get=error_message_get />
+            <div class="flex flex-col gap-1 mt-2">
+                {move || {
+                    let pace = pace_get.get();
+                    let start_time = start_time_get.get();
+                    let stops = stops_get.get();
+                    course_get
+                        .get()
+                        .into_iter()
+                        .map(|checkpoint| {
+                            let (arrival, margin, background) = if !pace.is_zero() {
+                                let arrival_time = checkpoint.arrival(pace, &stops);
+                                let arrival = match start_time {
+                                    Some(start_time) => {
+                                        format!(
+                                            "{} ({})",
+                                            arrival_time,
+                                            format_clock_time(start_time, arrival_time),
+                                        )
+                                    }
+                                    None => arrival_time.to_string(),
+                                };
+                                match checkpoint.margin(arrival_time, start_time) {
+                                    Some((margin, status)) => {
+                                        let background = match status {
+                                            CutoffStatus::Safe => "transparent",
+                                            CutoffStatus::Tight => colors::ORANGE,
+                                            CutoffStatus::Missed => colors::RED,
+                                        };
+                                        (arrival, format!("margin {}", format_margin(margin)), background)
+                                    }
+                                    None => (arrival, "needs start time".to_string(), "transparent"),
+                                }
+                            } else {
+                                ("—".to_string(), "—".to_string(), "transparent")
+                            };
+                            view! {
+                                <div
+                                    class="px-2 rounded whitespace-nowrap"
+                                    style=format!("background-color: {};", background)
+                                >
+                                    {format!(
+                                        "{} ({}): arrival {}, {}",
+                                        checkpoint.name,
+                                        checkpoint.distance,
+                                        arrival,
+                                        margin,
+                                    )}
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        </details>
+    }
+}
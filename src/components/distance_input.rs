@@ -21,39 +21,55 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::components::{FieldError, FieldHint};
 use crate::form_state::FormState;
+use crate::input_text::InputText;
+use crate::units::Distance;
 use leptos::prelude::*;
 use std::collections::HashMap;
 
 #[component]
 pub fn DistanceInput(
     id: usize,
-    distance_get: ReadSignal<usize>,
-    distance_set: WriteSignal<usize>,
+    distance_get: ReadSignal<Distance>,
+    distance_set: WriteSignal<Distance>,
+    error_message_get: ReadSignal<String>,
     error_message_set: WriteSignal<String>,
     set_form_states: WriteSignal<HashMap<usize, FormState>>,
-    #[prop(default = false)] is_grid: bool,
 ) -> impl IntoView {
+    let text = InputText::new(distance_get, |distance| {
+        if distance.is_zero() {
+            String::new()
+        } else {
+            distance.as_meters().to_string()
+        }
+    });
+
     let handle_input = move |ev| {
         let input_value = event_target_value(&ev);
-        if input_value.trim().is_empty() {
-            distance_set.set(0);
+        let valid = if input_value.trim().is_empty() {
+            distance_set.set(Distance::ZERO);
             error_message_set.set(String::new());
+            true
         } else {
-            match input_value.parse::<usize>() {
+            match input_value.parse::<Distance>() {
                 Ok(value) => {
-                    if value == 0 {
+                    if value.is_zero() {
                         error_message_set.set("Distance must be greater than 0".to_string());
+                        false
                     } else {
                         distance_set.set(value);
                         error_message_set.set(String::new());
+                        true
                     }
                 }
-                Err(_) => {
-                    error_message_set.set("Distance must be a positive number".to_string());
+                Err(err) => {
+                    error_message_set.set(format!("Distance error: {}", err));
+                    false
                 }
             }
-        }
+        };
+        text.input(input_value, valid);
         set_form_states.update(|states| {
             if let Some(state) = states.get_mut(&id) {
                 state.distance = distance_get.get();
@@ -61,34 +77,31 @@ pub fn DistanceInput(
         });
     };
 
-    if is_grid {
-        view! {
-            <div class="flex items-center">
-                <span class="whitespace-nowrap">"Distance (m):"</span>
-            </div>
-            <input 
-                type="number" 
-                inputmode="numeric"
-                pattern="[0-9]*"
-                class="w-32 px-2 py-1 rounded" 
-                on:input=handle_input 
-            />
-        }
-        .into_any()
-    } else {
-        view! {
-            <div>
-                <label>
-                    "Distance (m): " 
-                    <input 
-                        type="number" 
-                        inputmode="numeric"
-                        pattern="[0-9]*"
-                        on:input=handle_input 
-                    />
-                </label>
-            </div>
-        }
-        .into_any()
+    let error_id = format!("form-{}-distance-error", id);
+    let hint_id = format!("form-{}-distance-hint", id);
+    let hint = move || {
+        text.interpreted()
+            .map(|meters| format!("Interpreted as {} m", meters))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <label class="contents md:flex md:items-center md:gap-1">
+            <span class="whitespace-nowrap">"Distance (m):"</span>
+            <span class="flex flex-col">
+                <input
+                    type="text"
+                    class="w-32 px-2 py-1 rounded"
+                    prop:value=move || text.get()
+                    aria-invalid=move || (!error_message_get.get().is_empty()).to_string()
+                    aria-describedby=format!("{} {}", error_id, hint_id)
+                    on:input=handle_input
+                    on:focus=move |_| text.focus()
+                    on:blur=move |_| text.blur()
+                />
+                <FieldError id=error_id error_message_get=error_message_get />
+                <FieldHint id=hint_id hint=Signal::derive(hint) />
+            </span>
+        </label>
     }
 }
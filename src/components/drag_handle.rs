@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use leptos::prelude::*;
+
+/// Handle to move the form at `index`. Dropping it on another form or
+/// pressing the arrow keys runs `on_move` with the old and new index.
+#[component]
+pub fn DragHandle(
+    index: Memo<usize>,
+    on_move: Callback<(usize, usize)>,
+    dragging_set: WriteSignal<bool>,
+) -> impl IntoView {
+    let handle_ref = NodeRef::<leptos::html::Button>::new();
+
+    let handle_pointer_down = move |ev: web_sys::PointerEvent| {
+        if let Some(handle) = handle_ref.get() {
+            let _ = handle.set_pointer_capture(ev.pointer_id());
+        }
+        dragging_set.set(true);
+    };
+    let handle_pointer_up = move |ev: web_sys::PointerEvent| {
+        dragging_set.set(false);
+        let index = index.get_untracked();
+        let target_index = document()
+            .element_from_point(ev.client_x() as f32, ev.client_y() as f32)
+            .and_then(|element| element.closest("[data-form-index]").ok().flatten())
+            .and_then(|form| form.get_attribute("data-form-index"))
+            .and_then(|target_index| target_index.parse::<usize>().ok());
+        if let Some(target_index) = target_index {
+            if target_index != index {
+                on_move.run((index, target_index));
+            }
+        }
+    };
+    let handle_key_down = move |ev: web_sys::KeyboardEvent| {
+        let index = index.get_untracked();
+        let target_index = match ev.key().as_str() {
+            "ArrowUp" => index.checked_sub(1),
+            "ArrowDown" => Some(index + 1),
+            _ => None,
+        };
+        if let Some(target_index) = target_index {
+            ev.prevent_default();
+            on_move.run((index, target_index));
+        }
+    };
+
+    view! {
+        <button
+            node_ref=handle_ref
+            data-drag-handle=""
+            title="Drag or use the arrow keys to move"
+            aria-label="Move form, use the arrow keys to move up or down"
+            on:pointerdown=handle_pointer_down
+            on:pointerup=handle_pointer_up
+            on:pointercancel=move |_| dragging_set.set(false)
+            on:keydown=handle_key_down
+            style=format!(
+                "background: transparent; border: none; padding: 5px; border-radius: 3px; cursor: grab; touch-action: none; color: {};",
+                colors::WHITE,
+            )
+        >
+            <svg
+                xmlns="http://www.w3.org/2000/svg"
+                width="16"
+                height="16"
+                fill="currentColor"
+                viewBox="0 0 16 16"
+            >
+                <path d="M7 2a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm3 0a1 1 0 1 1-2 0 1 1 0 0 1 2 0zM7 5a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm3 0a1 1 0 1 1-2 0 1 1 0 0 1 2 0zM7 8a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm3 0a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm-3 3a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm3 0a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm-3 3a1 1 0 1 1-2 0 1 1 0 0 1 2 0zm3 0a1 1 0 1 1-2 0 1 1 0 0 1 2 0z" />
+            </svg>
+        </button>
+    }
+}
@@ -25,9 +25,10 @@ use crate::colors;
 use leptos::prelude::*;
 
 #[component]
-pub fn ErrorMessage(error_message_get: ReadSignal<String>) -> impl IntoView {
+pub fn ErrorMessage(#[prop(into)] error_message_get: Signal<String>) -> impl IntoView {
     view! {
         <div
+            role="status"
             style:color="red"
             style:background-color=colors::GREY
             style:border-radius="5px"
@@ -38,7 +38,7 @@ pub fn LabelInput(
                 <span class="mr-2">"Label:"</span>
                 <input
                     class="w-32 font-bold px-2 py-1 rounded"
-                    value={label_get}
+                    prop:value=label_get
                     on:input=move |ev| {
                         let input_value = event_target_value(&ev);
                         label_set.set(input_value);
@@ -21,24 +21,56 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
+mod activity_comparison;
+mod course_checkpoints;
 mod delete_button;
 mod distance_input;
+mod drag_handle;
+mod duplicate_button;
 mod error_message;
+mod export_csv_button;
+mod export_fit_button;
+mod field_error;
+mod field_hint;
 mod label_input;
+mod multisport_planner;
+mod pace_band;
 mod pace_calculator_form;
 mod pace_input;
+mod pace_table;
+mod plan_library;
+mod rowing_calculator;
 mod split_toggle;
 mod splits_input;
 mod splits_list;
+mod start_time_input;
+mod stops_input;
 mod total_duration;
+mod treadmill_table;
 
+pub use activity_comparison::ActivityComparison;
+pub use course_checkpoints::CourseCheckpoints;
 pub use delete_button::DeleteButton;
 pub use distance_input::DistanceInput;
+pub use drag_handle::DragHandle;
+pub use duplicate_button::DuplicateButton;
 pub use error_message::ErrorMessage;
+pub use export_csv_button::ExportCsvButton;
+pub use export_fit_button::ExportFitButton;
+pub use field_error::FieldError;
+pub use field_hint::FieldHint;
+pub use label_input::LabelInput;
+pub use multisport_planner::MultisportPlanner;
+pub use pace_band::PaceBand;
 pub use pace_calculator_form::PaceCalculatorForm;
 pub use pace_input::PaceInput;
+pub use pace_table::PaceTable;
+pub use plan_library::PlanLibrary;
+pub use rowing_calculator::RowingCalculator;
 pub use split_toggle::SplitToggle;
 pub use splits_input::SplitsInput;
 pub use splits_list::SplitsList;
+pub use start_time_input::StartTimeInput;
+pub use stops_input::StopsInput;
 pub use total_duration::TotalDuration;
-pub use label_input::LabelInput;
+pub use treadmill_table::TreadmillTable;
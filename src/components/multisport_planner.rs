@@ -0,0 +1,305 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::components::{ErrorMessage, SplitsList};
+use crate::input_text::InputText;
+use crate::multisport::{bike_time, run_time, swim_time, with_cumulative, PRESETS};
+use crate::pace::parse_pace;
+use crate::units::{Distance, Pace, Speed, TimeSpan};
+use leptos::prelude::*;
+
+fn parse_optional_duration(s: &str, field: &str) -> Result<TimeSpan, String> {
+    if s.trim().is_empty() {
+        Ok(TimeSpan::ZERO)
+    } else {
+        s.trim()
+            .parse::<TimeSpan>()
+            .map_err(|err| format!("{} error: {}", field, err))
+    }
+}
+
+fn parse_distance(s: &str, field: &str) -> Result<Distance, String> {
+    if s.trim().is_empty() {
+        Ok(Distance::ZERO)
+    } else {
+        s.trim()
+            .parse::<usize>()
+            .map(Distance::meters)
+            .map_err(|_| format!("{} must be a positive number", field))
+    }
+}
+
+#[component]
+pub fn MultisportPlanner() -> impl IntoView {
+    let (swim_distance_get, swim_distance_set) = signal(PRESETS[1].swim_distance);
+    let (swim_pace_get, swim_pace_set) = signal(TimeSpan::ZERO);
+    let (t1_get, t1_set) = signal(TimeSpan::ZERO);
+    let (bike_distance_get, bike_distance_set) = signal(PRESETS[1].bike_distance);
+    let (bike_speed_get, bike_speed_set) = signal(Speed::default());
+    let (t2_get, t2_set) = signal(TimeSpan::ZERO);
+    let (run_distance_get, run_distance_set) = signal(PRESETS[1].run_distance);
+    let (run_pace_get, run_pace_set) = signal(Pace::ZERO);
+    let (run_splits_get, run_splits_set) = signal(Distance::meters(1000));
+    let (error_message_get, error_message_set) = signal(String::new());
+
+    let legs = Memo::new(move |_| {
+        with_cumulative(vec![
+            (
+                "Swim",
+                swim_time(swim_distance_get.get(), swim_pace_get.get()),
+            ),
+            ("T1", Some(t1_get.get())),
+            (
+                "Bike",
+                bike_time(bike_distance_get.get(), bike_speed_get.get()),
+            ),
+            ("T2", Some(t2_get.get())),
+            ("Run", run_time(run_distance_get.get(), run_pace_get.get())),
+        ])
+    });
+
+    let duration_input =
+        move |label: &'static str, placeholder: &'static str, set: Callback<TimeSpan>| {
+            view! {
+                <label>
+                    {format!("{}: ", label)}
+                    <input
+                        type="text"
+                        pattern="[0-9:]+"
+                        placeholder=placeholder
+                        class="w-20"
+                        on:input=move |ev| {
+                            match parse_optional_duration(&event_target_value(&ev), label) {
+                                Ok(duration) => {
+                                    set.run(duration);
+                                    error_message_set.set(String::new());
+                                }
+                                Err(err) => error_message_set.set(err),
+                            }
+                        }
+                    />
+                </label>
+            }
+        };
+    let distance_input =
+        move |label: &'static str, get: ReadSignal<Distance>, set: WriteSignal<Distance>| {
+            let text = InputText::new(get, |distance| {
+                if distance.is_zero() {
+                    String::new()
+                } else {
+                    distance.as_meters().to_string()
+                }
+            });
+            view! {
+                <label>
+                    {format!("{}: ", label)}
+                    <input
+                        type="number"
+                        inputmode="numeric"
+                        pattern="[0-9]*"
+                        class="w-24"
+                        prop:value=move || text.get()
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev);
+                            let valid = match parse_distance(&value, label) {
+                                Ok(distance) => {
+                                    set.set(distance);
+                                    error_message_set.set(String::new());
+                                    true
+                                }
+                                Err(err) => {
+                                    error_message_set.set(err);
+                                    false
+                                }
+                            };
+                            text.input(value, valid);
+                        }
+                        on:focus=move |_| text.focus()
+                        on:blur=move |_| text.blur()
+                    />
+                </label>
+            }
+        };
+
+    view! {
+        <details class="mt-4">
+            <summary class="cursor-pointer">"Multisport"</summary>
+            <div
+                class="border border-solid border-gray-300 p-4 mt-2 rounded-md text-white flex flex-col gap-3"
+                style=format!("background-color: {}", colors::BLUE1)
+            >
+                <label>
+                    "Race: "
+                    <select
+                        style=format!(
+                            "color: {}; background-color: {}; border-radius: 3px;",
+                            colors::WHITE,
+                            colors::BLUE4,
+                        )
+                        on:change=move |ev| {
+                            if let Some(preset) = PRESETS
+                                .iter()
+                                .find(|preset| preset.name == event_target_value(&ev))
+                            {
+                                swim_distance_set.set(preset.swim_distance);
+                                bike_distance_set.set(preset.bike_distance);
+                                run_distance_set.set(preset.run_distance);
+                            }
+                        }
+                    >
+                        {PRESETS
+                            .iter()
+                            .enumerate()
+                            .map(|(index, preset)| {
+                                view! {
+                                    <option value=preset.name selected=index == 1>
+                                        {preset.name}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <div class="flex flex-wrap gap-5 items-center">
+                    {distance_input("Swim (m)", swim_distance_get, swim_distance_set)}
+                    {duration_input(
+                        "Swim pace (mm:ss/100m)",
+                        "mm:ss",
+                        Callback::new(move |pace| swim_pace_set.set(pace)),
+                    )}
+                    {duration_input("T1 (mm:ss)", "mm:ss", Callback::new(move |time| t1_set.set(time)))}
+                </div>
+                <div class="flex flex-wrap gap-5 items-center">
+                    {distance_input("Bike (m)", bike_distance_get, bike_distance_set)}
+                    <label>
+                        "Bike speed (km/h): "
+                        <input
+                            type="text"
+                            inputmode="decimal"
+                            class="w-20"
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev).replace(',', ".");
+                                if value.trim().is_empty() {
+                                    bike_speed_set.set(Speed::default());
+                                    error_message_set.set(String::new());
+                                } else {
+                                    match value.trim().parse::<f64>() {
+                                        Ok(speed) if speed > 0.0 => {
+                                            bike_speed_set.set(Speed::kmh(speed));
+                                            error_message_set.set(String::new());
+                                        }
+                                        _ => {
+                                            error_message_set
+                                                .set("Bike speed must be a positive number".to_string());
+                                        }
+                                    }
+                                }
+                            }
+                        />
+                    </label>
+                    {duration_input("T2 (mm:ss)", "mm:ss", Callback::new(move |time| t2_set.set(time)))}
+                </div>
+                <div class="flex flex-wrap gap-5 items-center">
+                    {distance_input("Run (m)", run_distance_get, run_distance_set)}
+                    <label>
+                        "Run pace (/km): "
+                        <input
+                            type="text"
+                            placeholder="m:ss"
+                            class="w-20"
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                if value.trim().is_empty() {
+                                    run_pace_set.set(Pace::ZERO);
+                                    error_message_set.set(String::new());
+                                } else {
+                                    match parse_pace(&value) {
+                                        Ok(pace) => {
+                                            run_pace_set.set(pace);
+                                            error_message_set.set(String::new());
+                                        }
+                                        Err(err) => {
+                                            error_message_set.set(format!("Run pace error: {}", err));
+                                        }
+                                    }
+                                }
+                            }
+                        />
+                    </label>
+                    {distance_input("Run splits (m)", run_splits_get, run_splits_set)}
+                </div>
+                <ErrorMessage error_message_get=error_message_get />
+                <table class="text-left">
+                    <thead>
+                        <tr>
+                            <th class="pr-4">"Leg"</th>
+                            <th class="pr-4">"Time"</th>
+                            <th class="pr-4">"Cumulative"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            legs.get()
+                                .into_iter()
+                                .map(|(name, time, cumulative)| {
+                                    view! {
+                                        <tr>
+                                            <td class="pr-4">{name}</td>
+                                            <td class="pr-4">
+                                                {time.map(|time| time.to_string()).unwrap_or_else(|| "—".to_string())}
+                                            </td>
+                                            <td class="pr-4">
+                                                {cumulative
+                                                    .map(|time| time.to_string())
+                                                    .unwrap_or_else(|| "—".to_string())}
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+                <div class="font-bold">
+                    "Finish: "
+                    {move || {
+                        legs.get()
+                            .last()
+                            .and_then(|(_, _, cumulative)| *cumulative)
+                            .map(|time| time.to_string())
+                            .unwrap_or_else(|| "—".to_string())
+                    }}
+                </div>
+                <div class="flex items-baseline">
+                    "Run splits:"
+                    <SplitsList
+                        pace_get=run_pace_get
+                        distance_get=run_distance_get
+                        splits_get=run_splits_get
+                    />
+                </div>
+            </div>
+        </details>
+    }
+}
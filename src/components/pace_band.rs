@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::components::ErrorMessage;
+use crate::files::download;
+use crate::form_state::FormState;
+use crate::pace_band::{pace_band_svg, BAND_WIDTHS, MIN_MARKER};
+use crate::units::Distance;
+use leptos::prelude::*;
+
+fn print_svg(svg: &str) {
+    let Ok(Some(print_window)) = window().open_with_url_and_target("", "_blank") else {
+        return;
+    };
+    if let Some(body) = print_window.document().and_then(|document| document.body()) {
+        body.set_inner_html(&format!(
+            "<style>@page {{ margin: 10mm; }} body {{ margin: 0; }}</style>{}",
+            svg
+        ));
+        let _ = print_window.print();
+    }
+}
+
+#[component]
+pub fn PaceBand(form_state: Memo<FormState>) -> impl IntoView {
+    let (width_get, width_set) = signal(BAND_WIDTHS[1]);
+    let (marker_get, marker_set) = signal(Distance::meters(1000));
+    let (error_message_get, error_message_set) = signal(String::new());
+    let svg = Memo::new(move |_| {
+        form_state.with(|state| {
+            (!state.pace.is_zero() && !state.distance.is_zero())
+                .then(|| pace_band_svg(state, width_get.get(), marker_get.get()))
+        })
+    });
+    let button_style = format!(
+        "background-color:{}; color: white; border: none; padding: 3px 10px; border-radius: 5px; cursor: pointer;",
+        colors::BLUE4,
+    );
+
+    view! {
+        <details class="mt-2">
+            <summary class="cursor-pointer">"Pace band"</summary>
+            <div class="flex flex-wrap gap-5 items-center my-2">
+                <label>
+                    "Width: "
+                    <select
+                        style=format!(
+                            "color: {}; background-color: {}; border-radius: 3px;",
+                            colors::WHITE,
+                            colors::BLUE4,
+                        )
+                        on:change=move |ev| {
+                            if let Ok(width) = event_target_value(&ev).parse::<u32>() {
+                                width_set.set(width);
+                            }
+                        }
+                    >
+                        {BAND_WIDTHS
+                            .iter()
+                            .map(|&width| {
+                                view! {
+                                    <option value=width.to_string() selected=width == BAND_WIDTHS[1]>
+                                        {format!("{} mm", width)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <label>
+                    "Markers every (m): "
+                    <input
+                        type="number"
+                        inputmode="numeric"
+                        pattern="[0-9]*"
+                        class="w-20"
+                        prop:value=move || marker_get.get().as_meters().to_string()
+                        on:input=move |ev| {
+                            match event_target_value(&ev).parse::<Distance>() {
+                                Ok(marker) if marker >= MIN_MARKER => {
+                                    marker_set.set(marker);
+                                    error_message_set.set(String::new());
+                                }
+                                _ => {
+                                    error_message_set
+                                        .set(format!("Markers must be at least {} apart", MIN_MARKER));
+                                }
+                            }
+                        }
+                    />
+                </label>
+                <button
+                    style=button_style.clone()
+                    on:click=move |_| {
+                        if let Some(svg) = svg.get() {
+                            download("pace-band.svg", "image/svg+xml", svg.as_bytes());
+                        }
+                    }
+                >
+                    "Download SVG"
+                </button>
+                <button
+                    style=button_style
+                    on:click=move |_| {
+                        if let Some(svg) = svg.get() {
+                            print_svg(&svg);
+                        }
+                    }
+                >
+                    "Print"
+                </button>
+            </div>
+            <ErrorMessage error_message_get=error_message_get />
+            {move || match svg.get() {
+                Some(svg) => {
+                    view! { <div class="overflow-y-auto" style="max-height: 300px;" inner_html=svg></div> }
+                        .into_any()
+                }
+                None => view! { <div>"Enter pace and distance to create a pace band."</div> }.into_any(),
+            }}
+        </details>
+    }
+}
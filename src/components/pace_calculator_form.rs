@@ -23,18 +23,35 @@
 
 use crate::colors;
 use crate::components::{
-    DeleteButton, DistanceInput, ErrorMessage, LabelInput, PaceInput, SplitToggle, SplitsInput,
-    SplitsList, TotalDuration,
+    CourseCheckpoints, DeleteButton, DistanceInput, DragHandle, DuplicateButton, ErrorMessage,
+    ExportCsvButton, ExportFitButton, LabelInput, PaceBand, PaceInput, SplitToggle, SplitsInput,
+    SplitsList, StartTimeInput, StopsInput, TotalDuration, TreadmillTable,
 };
 use crate::form_state::FormState;
+use crate::stops::stop_time_before;
 use leptos::prelude::*;
 use std::collections::HashMap;
-use std::time::Duration;
+
+/// Sets the signal to `value` and returns whether it was different.
+fn sync<T: PartialEq + Send + Sync + 'static>(
+    get: ReadSignal<T>,
+    set: WriteSignal<T>,
+    value: T,
+) -> bool {
+    let changed = get.with_untracked(|current| *current != value);
+    if changed {
+        set.set(value);
+    }
+    changed
+}
 
 #[component]
 pub fn PaceCalculatorForm(
     id: usize,
-    on_delete: Option<Callback<usize>>,
+    index: Memo<usize>,
+    on_move: Callback<(usize, usize)>,
+    on_delete: Callback<usize>,
+    on_duplicate: Callback<usize>,
     form_state: Memo<FormState>,
     set_form_states: WriteSignal<HashMap<usize, FormState>>,
 ) -> impl IntoView {
@@ -42,110 +59,142 @@ pub fn PaceCalculatorForm(
     let (distance_get, distance_set) = signal(form_state.get().distance);
     let (show_splits_get, show_splits_set) = signal(form_state.get().show_splits);
     let (pace_get, pace_set) = signal(form_state.get().pace);
-    let (error_message_get, error_message_set) = signal(String::new());
+    let (pace_error_get, pace_error_set) = signal(String::new());
+    let (splits_error_get, splits_error_set) = signal(String::new());
+    let (distance_error_get, distance_error_set) = signal(String::new());
+    let (start_time_error_get, start_time_error_set) = signal(String::new());
+    let (stops_error_get, stops_error_set) = signal(String::new());
+    let (course_error_get, course_error_set) = signal(String::new());
+    let error_summary = Memo::new(move |_| {
+        [
+            pace_error_get,
+            splits_error_get,
+            distance_error_get,
+            start_time_error_get,
+            stops_error_get,
+            course_error_get,
+        ]
+        .iter()
+        .map(|error| error.get())
+        .filter(|error| !error.is_empty())
+        .collect::<Vec<_>>()
+        .join("; ")
+    });
+    let (dragging_get, dragging_set) = signal(false);
     let (label_get, label_set) = signal(form_state.get().label);
-    let total_duration = Memo::new(move |_| {
+    let (start_time_get, start_time_set) = signal(form_state.get().start_time);
+    let (course_get, course_set) = signal(form_state.get().course);
+    let (stops_get, stops_set) = signal(form_state.get().stops);
+
+    // Takes over changes of the state made outside of the form's inputs,
+    // for example by undo, so that the inputs show them. The error of a
+    // field that is replaced this way no longer applies.
+    Effect::new(move |_| {
+        let state = form_state.get();
+        let clear_error = |changed: bool, error_set: WriteSignal<String>| {
+            if changed {
+                error_set.set(String::new());
+            }
+        };
+        clear_error(sync(splits_get, splits_set, state.splits), splits_error_set);
+        clear_error(
+            sync(distance_get, distance_set, state.distance),
+            distance_error_set,
+        );
+        sync(show_splits_get, show_splits_set, state.show_splits);
+        clear_error(sync(pace_get, pace_set, state.pace), pace_error_set);
+        sync(label_get, label_set, state.label);
+        clear_error(
+            sync(start_time_get, start_time_set, state.start_time),
+            start_time_error_set,
+        );
+        clear_error(sync(course_get, course_set, state.course), course_error_set);
+        clear_error(sync(stops_get, stops_set, state.stops), stops_error_set);
+    });
+
+    let moving_duration = Memo::new(move |_| {
         let pace = pace_get.get();
         let distance = distance_get.get();
-        let seconds = if pace > Duration::ZERO && distance > 0 {
-            Some((distance as f64 / 1000.0) * pace.as_secs_f64())
-        } else {
-            None
-        };
-        seconds.map(Duration::from_secs_f64)
+        (!pace.is_zero() && !distance.is_zero()).then(|| pace * distance)
+    });
+    let total_duration = Memo::new(move |_| {
+        moving_duration.get().map(|moving| {
+            moving + stops_get.with(|stops| stop_time_before(stops, distance_get.get()))
+        })
     });
 
     view! {
         <div
             class="border border-solid border-gray-300 p-4 mb-5 rounded-md text-white"
-            style=format!("background-color: {}", colors::BLUE1)
+            data-form-index=move || index.get()
+            style=move || {
+                format!(
+                    "background-color: {}; opacity: {};",
+                    colors::BLUE1,
+                    if dragging_get.get() { 0.5 } else { 1.0 },
+                )
+            }
         >
             <div class="flex flex-col md:flex-row flex-wrap gap-5 mb-4 items-start justify-between">
-                <div class="w-full flex justify-end md:hidden">
-                    <LabelInput
-                        id=id
-                        label_get=label_get
-                        label_set=label_set
-                        set_form_states=set_form_states.clone()
-                    />
-                    {move || match on_delete.clone() {
-                        Some(callback) => {
-                            view! { <DeleteButton id=id callback=callback /> }.into_any()
-                        }
-                        None => view! { <></> }.into_any(),
-                    }}
-                </div> {}
                 <div
-                    class="w-full md:hidden grid"
-                    style="grid-template-columns: max-content 1fr; gap: 0.75rem; row-gap: 0.75rem; align-items: center;"
+                    class="w-full grid gap-3 items-center md:flex md:flex-wrap md:gap-5 md:flex-1 md:w-auto"
+                    style="grid-template-columns: max-content 1fr;"
                 >
                     <PaceInput
                         id=id
                         pace_get=pace_get
                         pace_set=pace_set
-                        error_message_set=error_message_set
+                        error_message_get=pace_error_get
+                        error_message_set=pace_error_set
                         set_form_states=set_form_states.clone()
-                        is_grid=true
                     />
-
                     <SplitsInput
                         id=id
                         splits_get=splits_get
                         splits_set=splits_set
-                        error_message_set=error_message_set
+                        error_message_get=splits_error_get
+                        error_message_set=splits_error_set
                         set_form_states=set_form_states.clone()
-                        is_grid=true
                     />
-
                     <DistanceInput
                         id=id
                         distance_get=distance_get
                         distance_set=distance_set
-                        error_message_set=error_message_set
-                        set_form_states=set_form_states.clone()
-                        is_grid=true
-                    />
-
-                    <TotalDuration total_duration=total_duration is_grid=true />
-                </div> <div class="hidden md:flex md:flex-wrap md:gap-5 md:items-center md:flex-1">
-                    <PaceInput
-                        id=id
-                        pace_get=pace_get
-                        pace_set=pace_set
-                        error_message_set=error_message_set
+                        error_message_get=distance_error_get
+                        error_message_set=distance_error_set
                         set_form_states=set_form_states.clone()
                     />
-                    <SplitsInput
+                    <StartTimeInput
                         id=id
-                        splits_get=splits_get
-                        splits_set=splits_set
-                        error_message_set=error_message_set
+                        start_time_get=start_time_get
+                        start_time_set=start_time_set
+                        error_message_get=start_time_error_get
+                        error_message_set=start_time_error_set
                         set_form_states=set_form_states.clone()
                     />
-                    <DistanceInput
-                        id=id
-                        distance_get=distance_get
-                        distance_set=distance_set
-                        error_message_set=error_message_set
-                        set_form_states=set_form_states.clone()
+                    <TotalDuration
+                        total_duration=total_duration
+                        moving_duration=moving_duration
+                        start_time_get=start_time_get
                     />
-                    <TotalDuration total_duration=total_duration />
-                </div> <div class="hidden md:flex md:w-auto items-center justify-end">
+                </div>
+                <div class="w-full md:w-auto flex items-center justify-end order-first md:order-none">
+                    <DragHandle index=index on_move=on_move dragging_set=dragging_set />
                     <LabelInput
                         id=id
                         label_get=label_get
                         label_set=label_set
                         set_form_states=set_form_states.clone()
                     />
-                    {move || match on_delete.clone() {
-                        Some(callback) => {
-                            view! { <DeleteButton id=id callback=callback /> }.into_any()
-                        }
-                        None => view! { <></> }.into_any(),
-                    }}
+                    <ExportCsvButton form_state=form_state />
+                    <ExportFitButton form_state=form_state />
+                    <DuplicateButton id=id callback=on_duplicate />
+                    <Show when=move || { index.get() > 0 }>
+                        <DeleteButton id=id callback=on_delete />
+                    </Show>
                 </div>
             </div>
-            <ErrorMessage error_message_get=error_message_get />
+            <ErrorMessage error_message_get=error_summary />
             <div class="flex items-baseline">
                 <SplitToggle
                     id=id
@@ -158,8 +207,31 @@ pub fn PaceCalculatorForm(
                     distance_get=distance_get
                     splits_get=splits_get
                     show_splits_get=show_splits_get
+                    start_time_get=start_time_get
+                    stops_get=stops_get
                 />
             </div>
+            <StopsInput
+                id=id
+                stops_get=stops_get
+                stops_set=stops_set
+                error_message_get=stops_error_get
+                error_message_set=stops_error_set
+                set_form_states=set_form_states.clone()
+            />
+            <CourseCheckpoints
+                id=id
+                pace_get=pace_get
+                start_time_get=start_time_get
+                stops_get=stops_get
+                course_get=course_get
+                course_set=course_set
+                error_message_get=course_error_get
+                error_message_set=course_error_set
+                set_form_states=set_form_states.clone()
+            />
+            <TreadmillTable pace_get=pace_get />
+            <PaceBand form_state=form_state />
         </div>
     }
 }
@@ -21,50 +21,50 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::components::{FieldError, FieldHint};
 use crate::form_state::FormState;
+use crate::input_text::InputText;
+use crate::units::Pace;
 use leptos::prelude::*;
 use std::collections::HashMap;
-use std::time::Duration;
-
-fn parse_duration(s: &str) -> Result<Duration, String> {
-    let parts: Vec<&str> = s.split(':').collect();
-    if parts.len() != 2 {
-        return Err("Invalid format".to_string());
-    }
-    let minutes: u64 = parts[0]
-        .parse()
-        .map_err(|_| "Invalid minutes".to_string())?;
-    let seconds: u64 = parts[1]
-        .parse()
-        .map_err(|_| "Invalid seconds".to_string())?;
-    Ok(Duration::from_secs(minutes * 60 + seconds))
-}
 
 #[component]
 pub fn PaceInput(
     id: usize,
-    pace_get: ReadSignal<Duration>,
-    pace_set: WriteSignal<Duration>,
+    pace_get: ReadSignal<Pace>,
+    pace_set: WriteSignal<Pace>,
+    error_message_get: ReadSignal<String>,
     error_message_set: WriteSignal<String>,
     set_form_states: WriteSignal<HashMap<usize, FormState>>,
-    #[prop(default = false)] is_grid: bool,
 ) -> impl IntoView {
+    let text = InputText::new(pace_get, |&pace| {
+        if !pace.is_zero() {
+            pace.to_string()
+        } else {
+            String::new()
+        }
+    });
+
     let handle_input = move |ev| {
         let pace_str = event_target_value(&ev);
-        if pace_str.trim().is_empty() {
-            pace_set.set(Duration::ZERO);
+        let valid = if pace_str.trim().is_empty() {
+            pace_set.set(Pace::ZERO);
             error_message_set.set(String::new());
+            true
         } else {
-            match parse_duration(&pace_str) {
-                Ok(duration) => {
-                    pace_set.set(duration);
+            match pace_str.parse::<Pace>() {
+                Ok(pace) => {
+                    pace_set.set(pace);
                     error_message_set.set(String::new());
+                    true
                 }
                 Err(err) => {
                     error_message_set.set(format!("Pace error: {}", err));
+                    false
                 }
             }
-        }
+        };
+        text.input(pace_str, valid);
         set_form_states.update(|states| {
             if let Some(state) = states.get_mut(&id) {
                 state.pace = pace_get.get();
@@ -72,34 +72,32 @@ pub fn PaceInput(
         });
     };
 
-    if is_grid {
-        view! {
-            <div class="flex items-center">
-                <span class="whitespace-nowrap">"Pace (mm:ss/km):"</span>
-            </div>
-            <input 
-                type="text" 
-                pattern="[0-9:]+"
-                placeholder="mm:ss"
-                class="w-32 px-2 py-1 rounded" 
-                on:input=handle_input 
-            />
-        }
-        .into_any()
-    } else {
-        view! {
-            <div>
-                <label>
-                    "Pace (mm:ss/km): " 
-                    <input 
-                        type="text" 
-                        pattern="[0-9:]+"
-                        placeholder="mm:ss"
-                        on:input=handle_input 
-                    />
-                </label>
-            </div>
-        }
-        .into_any()
+    let error_id = format!("form-{}-pace-error", id);
+    let hint_id = format!("form-{}-pace-hint", id);
+    let hint = move || {
+        text.interpreted()
+            .map(|pace| format!("Interpreted as {} /km", pace))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <label class="contents md:flex md:items-center md:gap-1">
+            <span class="whitespace-nowrap">"Pace (mm:ss/km):"</span>
+            <span class="flex flex-col">
+                <input
+                    type="text"
+                    placeholder="mm:ss"
+                    class="w-32 px-2 py-1 rounded"
+                    prop:value=move || text.get()
+                    aria-invalid=move || (!error_message_get.get().is_empty()).to_string()
+                    aria-describedby=format!("{} {}", error_id, hint_id)
+                    on:input=handle_input
+                    on:focus=move |_| text.focus()
+                    on:blur=move |_| text.blur()
+                />
+                <FieldError id=error_id error_message_get=error_message_get />
+                <FieldHint id=hint_id hint=Signal::derive(hint) />
+            </span>
+        </label>
     }
 }
@@ -0,0 +1,186 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::units::{Distance, Pace};
+use leptos::prelude::*;
+use std::time::Duration;
+
+const MAX_ROWS: usize = 200;
+
+/// Parses distances separated by `;`, as a comma may be a decimal
+/// separator and a space may come before the unit.
+fn parse_distances(s: &str) -> Result<Vec<Distance>, String> {
+    s.split(';')
+        .map(str::trim)
+        .filter(|distance| !distance.is_empty())
+        .map(|distance| match distance.parse::<Distance>() {
+            Ok(value) if !value.is_zero() => Ok(value),
+            _ => Err(format!("Invalid distance: {}", distance)),
+        })
+        .collect()
+}
+
+fn paces(from: Pace, to: Pace, step: Duration) -> Vec<Pace> {
+    let mut paces = Vec::new();
+    let mut pace = from;
+    while pace <= to && paces.len() < MAX_ROWS {
+        paces.push(pace);
+        pace = pace + step;
+    }
+    paces
+}
+
+#[component]
+pub fn PaceTable() -> impl IntoView {
+    let (from_get, from_set) = signal("3:30".to_string());
+    let (to_get, to_set) = signal("6:00".to_string());
+    let (step_get, step_set) = signal("5".to_string());
+    let (distances_get, distances_set) = signal("5k; 10k; HM; M".to_string());
+
+    let table = Memo::new(move |_| {
+        let from = from_get
+            .get()
+            .parse::<Pace>()
+            .map_err(|err| format!("From: {}", err))?;
+        let to = to_get
+            .get()
+            .parse::<Pace>()
+            .map_err(|err| format!("To: {}", err))?;
+        let step = match step_get.get().trim().parse::<u64>() {
+            Ok(value) if value > 0 => Duration::from_secs(value),
+            _ => return Err("Step must be a positive number of seconds".to_string()),
+        };
+        if from.is_zero() || from > to {
+            return Err("From must be a pace faster than To".to_string());
+        }
+        let distances = parse_distances(&distances_get.get())?;
+        Ok((paces(from, to, step), distances))
+    });
+
+    let input_style = "w-20 px-2 py-1 rounded";
+
+    view! {
+        <details class="pace-table mt-4">
+            <summary class="cursor-pointer print:hidden">"Pace table"</summary>
+            <div class="flex flex-wrap gap-5 items-center my-2 print:hidden">
+                <label>
+                    "From (mm:ss/km): "
+                    <input
+                        type="text"
+                        class=input_style
+                        prop:value=from_get
+                        on:input=move |ev| from_set.set(event_target_value(&ev))
+                    />
+                </label>
+                <label>
+                    "To (mm:ss/km): "
+                    <input
+                        type="text"
+                        class=input_style
+                        prop:value=to_get
+                        on:input=move |ev| to_set.set(event_target_value(&ev))
+                    />
+                </label>
+                <label>
+                    "Step (s): "
+                    <input
+                        type="number"
+                        inputmode="numeric"
+                        class=input_style
+                        prop:value=step_get
+                        on:input=move |ev| step_set.set(event_target_value(&ev))
+                    />
+                </label>
+                <label>
+                    "Distances (separated by ;): "
+                    <input
+                        type="text"
+                        placeholder="5k; 10k; HM; M"
+                        class="w-64 px-2 py-1 rounded"
+                        prop:value=distances_get
+                        on:input=move |ev| distances_set.set(event_target_value(&ev))
+                    />
+                </label>
+                <button
+                    on:click=move |_| {
+                        // The print styles apply only while this class is set,
+                        // so printing the page otherwise prints all of it.
+                        let root = document().document_element();
+                        if let Some(root) = &root {
+                            let _ = root.class_list().add_1("printing-pace-table");
+                        }
+                        let _ = window().print();
+                        if let Some(root) = &root {
+                            let _ = root.class_list().remove_1("printing-pace-table");
+                        }
+                    }
+                    style=format!(
+                        "background-color:{}; color: white; border: none; padding: 3px 10px; border-radius: 5px; cursor: pointer;",
+                        colors::BLUE4,
+                    )
+                >
+                    "Print"
+                </button>
+            </div>
+            {move || match table.get() {
+                Ok((paces, distances)) => {
+                    view! {
+                        <table class="text-right">
+                            <thead>
+                                <tr>
+                                    <th class="px-2">"Pace"</th>
+                                    {distances
+                                        .iter()
+                                        .map(|distance| view! { <th class="px-2">{distance.to_string()}</th> })
+                                        .collect_view()}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {paces
+                                    .into_iter()
+                                    .map(|pace| {
+                                        let cells = distances
+                                            .iter()
+                                            .map(|&distance| {
+                                                view! { <td class="px-2">{(pace * distance).to_string()}</td> }
+                                            })
+                                            .collect_view();
+                                        view! {
+                                            <tr>
+                                                <th class="px-2">{pace.to_string()}</th>
+                                                {cells}
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </tbody>
+                        </table>
+                    }
+                        .into_any()
+                }
+                Err(err) => view! { <div style:color=colors::RED>{err}</div> }.into_any(),
+            }}
+        </details>
+    }
+}
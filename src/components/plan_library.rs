@@ -0,0 +1,276 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::components::ErrorMessage;
+use crate::form_state::FormState;
+use crate::library::Library;
+use crate::plan::Plan;
+use crate::storage::{load_draft, load_library, remove_draft, save_draft, save_library};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+#[component]
+pub fn PlanLibrary(
+    forms_get: ReadSignal<Vec<usize>>,
+    form_states_get: ReadSignal<HashMap<usize, FormState>>,
+    load_plan: Callback<Plan>,
+) -> impl IntoView {
+    let (library_get, library_set) = signal(load_library());
+    let (has_draft_get, has_draft_set) = signal(load_draft().is_some());
+    let (current_name_get, current_name_set) = signal(None::<String>);
+    let (name_get, name_set) = signal(String::new());
+    let (error_message_get, error_message_set) = signal(String::new());
+
+    let current_plan =
+        move || forms_get.with(|forms| form_states_get.with(|states| Plan::new(forms, states)));
+
+    // Applies `change` to the library and stores it, or shows its error.
+    let update_library = move |change: &dyn Fn(&mut Library) -> Result<(), String>| {
+        let mut library = library_get.get_untracked();
+        match change(&mut library) {
+            Ok(()) => {
+                save_library(&library);
+                library_set.set(library);
+                error_message_set.set(String::new());
+            }
+            Err(err) => error_message_set.set(err),
+        }
+    };
+
+    let save = move |_| {
+        let name = name_get.get_untracked();
+        let replaces_other = library_get
+            .with_untracked(|library| library.get(name.trim()).is_some())
+            && current_name_get.get_untracked().as_deref() != Some(name.trim());
+        if replaces_other
+            && !window()
+                .confirm_with_message(&format!("Replace the saved plan {}?", name.trim()))
+                .unwrap_or(false)
+        {
+            return;
+        }
+        update_library(&|library| {
+            let name = library.save(&name, &current_plan())?;
+            current_name_set.set(Some(name));
+            Ok(())
+        });
+    };
+
+    // Whether the working set differs from the plan it was opened from,
+    // so that replacing it would lose changes.
+    let has_changes = move || {
+        let working_set = current_plan();
+        !current_name_get
+            .get_untracked()
+            .and_then(|current| {
+                library_get.with_untracked(|library| library.get(&current).cloned())
+            })
+            .and_then(|current| current.plan().ok())
+            .is_some_and(|current| current == working_set)
+    };
+
+    // Keeps changed forms as draft. An existing draft is only replaced
+    // after asking; returns false if the user declines.
+    let keep_as_draft = move || {
+        if !has_changes() {
+            return true;
+        }
+        if has_draft_get.get_untracked()
+            && !window()
+                .confirm_with_message("Replace the earlier draft with your current forms?")
+                .unwrap_or(false)
+        {
+            return false;
+        }
+        save_draft(&current_plan());
+        has_draft_set.set(true);
+        true
+    };
+
+    let open = move |name: String| {
+        let Some(saved) = library_get.with_untracked(|library| library.get(&name).cloned()) else {
+            return;
+        };
+        match saved.plan() {
+            Ok(plan) => {
+                if !keep_as_draft() {
+                    return;
+                }
+                load_plan.run(plan);
+                name_set.set(name.clone());
+                current_name_set.set(Some(name));
+                error_message_set.set(String::new());
+            }
+            Err(err) => error_message_set.set(err),
+        }
+    };
+
+    // Swaps the draft with the working set, or drops the draft if the
+    // working set has no changes to keep.
+    let restore_draft = move |_| {
+        if let Some(draft) = load_draft() {
+            if has_changes() {
+                save_draft(&current_plan());
+            } else {
+                remove_draft();
+                has_draft_set.set(false);
+            }
+            load_plan.run(draft);
+            current_name_set.set(None);
+        }
+    };
+
+    let discard_draft = move |_| {
+        if window()
+            .confirm_with_message("Discard the draft?")
+            .unwrap_or(false)
+        {
+            remove_draft();
+            has_draft_set.set(false);
+        }
+    };
+
+    let rename = move |name: String| {
+        if let Ok(Some(new_name)) = window().prompt_with_message_and_default("New name", &name) {
+            update_library(&|library| {
+                let new_name = library.rename(&name, &new_name)?;
+                if current_name_get.get_untracked().as_deref() == Some(name.as_str()) {
+                    current_name_set.set(Some(new_name));
+                }
+                Ok(())
+            });
+        }
+    };
+
+    let duplicate = move |name: String| {
+        update_library(&|library| library.duplicate(&name).map(|_| ()));
+    };
+
+    let delete = move |name: String| {
+        if window()
+            .confirm_with_message(&format!("Delete the plan {}?", name))
+            .unwrap_or(false)
+        {
+            update_library(&|library| {
+                library.delete(&name);
+                Ok(())
+            });
+            if current_name_get.get_untracked().as_deref() == Some(name.as_str()) {
+                current_name_set.set(None);
+            }
+        }
+    };
+
+    let button_style = format!(
+        "background-color:{}; color: white; border: none; padding: 2px 8px; border-radius: 5px; cursor: pointer;",
+        colors::BLUE4,
+    );
+    let draft_button_style = button_style.clone();
+    let plan_button_style = button_style.clone();
+
+    view! {
+        <details class="mt-4">
+            <summary class="cursor-pointer">"Saved plans"</summary>
+            <div
+                class="border border-solid border-gray-300 p-4 mt-2 rounded-md text-white flex flex-col gap-3"
+                style=format!("background-color: {}", colors::BLUE1)
+            >
+                <div class="flex flex-wrap gap-3 items-center">
+                    <input
+                        type="text"
+                        placeholder="Plan name"
+                        prop:value=move || name_get.get()
+                        on:input=move |ev| name_set.set(event_target_value(&ev))
+                    />
+                    <button on:click=save style=button_style>
+                        "Save current forms"
+                    </button>
+                    <Show when=move || has_draft_get.get()>
+                        <button on:click=restore_draft style=draft_button_style.clone()>
+                            "Restore draft"
+                        </button>
+                        <button on:click=discard_draft style=draft_button_style.clone()>
+                            "Discard draft"
+                        </button>
+                    </Show>
+                </div>
+                <ErrorMessage error_message_get=error_message_get />
+                <div class="flex flex-col gap-1">
+                    {move || {
+                        let current_name = current_name_get.get();
+                        let button_style = plan_button_style.clone();
+                        library_get
+                            .get()
+                            .plans
+                            .into_iter()
+                            .map(|saved| {
+                                let name = saved.name;
+                                let name_class = if current_name.as_deref() == Some(name.as_str()) {
+                                    "font-bold"
+                                } else {
+                                    ""
+                                };
+                                let (open_name, rename_name, duplicate_name, delete_name) = (
+                                    name.clone(),
+                                    name.clone(),
+                                    name.clone(),
+                                    name.clone(),
+                                );
+                                view! {
+                                    <div class="flex flex-wrap gap-2 items-center">
+                                        <span class=name_class>{name}</span>
+                                        <button
+                                            on:click=move |_| open(open_name.clone())
+                                            style=button_style.clone()
+                                        >
+                                            "Open"
+                                        </button>
+                                        <button
+                                            on:click=move |_| rename(rename_name.clone())
+                                            style=button_style.clone()
+                                        >
+                                            "Rename"
+                                        </button>
+                                        <button
+                                            on:click=move |_| duplicate(duplicate_name.clone())
+                                            style=button_style.clone()
+                                        >
+                                            "Duplicate"
+                                        </button>
+                                        <button
+                                            on:click=move |_| delete(delete_name.clone())
+                                            style=button_style.clone()
+                                        >
+                                            "Delete"
+                                        </button>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+            </div>
+        </details>
+    }
+}
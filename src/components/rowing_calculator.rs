@@ -0,0 +1,185 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::components::ErrorMessage;
+use crate::rowing::{split_to_pace, split_to_watts, watts_to_split, ERG_DISTANCES, SPLIT_DISTANCE};
+use crate::splits::split_times;
+use crate::units::{Distance, TimeSpan};
+use leptos::prelude::*;
+
+#[component]
+pub fn RowingCalculator() -> impl IntoView {
+    let (split_get, split_set) = signal(TimeSpan::ZERO);
+    let (distance_get, distance_set) = signal(Distance::meters(2000));
+    let (error_message_get, error_message_set) = signal(String::new());
+
+    let handle_split_input = move |ev| {
+        let split_str = event_target_value(&ev);
+        if split_str.trim().is_empty() {
+            split_set.set(TimeSpan::ZERO);
+            error_message_set.set(String::new());
+        } else {
+            match split_str.parse::<TimeSpan>() {
+                Ok(split) => {
+                    split_set.set(split);
+                    error_message_set.set(String::new());
+                }
+                Err(err) => {
+                    error_message_set.set(format!("Split error: {}", err));
+                }
+            }
+        }
+    };
+    let handle_watts_input = move |ev| {
+        let watts_str = event_target_value(&ev);
+        if watts_str.trim().is_empty() {
+            split_set.set(TimeSpan::ZERO);
+            error_message_set.set(String::new());
+        } else {
+            match watts_str.trim().parse::<f64>() {
+                Ok(watts) if watts > 0.0 => {
+                    split_set.set(watts_to_split(watts));
+                    error_message_set.set(String::new());
+                }
+                _ => {
+                    error_message_set.set("Watts must be a positive number".to_string());
+                }
+            }
+        }
+    };
+    let formatted_split = move || {
+        let split = split_get.get();
+        if split > TimeSpan::ZERO {
+            format!("{} /500m, {:.0} W", split, split_to_watts(split))
+        } else {
+            "—".to_string()
+        }
+    };
+
+    view! {
+        <details class="mt-4">
+            <summary class="cursor-pointer">"Rowing"</summary>
+            <div
+                class="border border-solid border-gray-300 p-4 mt-2 rounded-md text-white flex flex-col gap-3"
+                style=format!("background-color: {}", colors::BLUE1)
+            >
+                <div class="flex flex-wrap gap-5 items-center">
+                    <label>
+                        "Split (mm:ss/500m): "
+                        <input
+                            type="text"
+                            pattern="[0-9:]+"
+                            placeholder="mm:ss"
+                            class="w-20"
+                            on:input=handle_split_input
+                        />
+                    </label>
+                    <label>
+                        "or watts: "
+                        <input
+                            type="number"
+                            inputmode="numeric"
+                            class="w-20"
+                            on:input=handle_watts_input
+                        />
+                    </label>
+                    <div>{formatted_split}</div>
+                </div>
+                <ErrorMessage error_message_get=error_message_get />
+                <table class="text-left">
+                    <thead>
+                        <tr>
+                            <th class="pr-4">"Distance"</th>
+                            <th class="pr-4">"Time"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let split = split_get.get();
+                            ERG_DISTANCES
+                                .iter()
+                                .map(|&distance| {
+                                    let time = if split > TimeSpan::ZERO {
+                                        (split_to_pace(split) * distance).to_string()
+                                    } else {
+                                        "—".to_string()
+                                    };
+                                    view! {
+                                        <tr>
+                                            <td class="pr-4">{distance.to_string()}</td>
+                                            <td class="pr-4">{time}</td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+                <div class="flex flex-wrap items-baseline gap-3">
+                    <label>
+                        "Splits for: "
+                        <select
+                            style=format!(
+                                "color: {}; background-color: {}; border-radius: 3px;",
+                                colors::WHITE,
+                                colors::BLUE4,
+                            )
+                            on:change=move |ev| {
+                                if let Ok(distance) = event_target_value(&ev).parse::<Distance>() {
+                                    distance_set.set(distance);
+                                }
+                            }
+                        >
+                            {ERG_DISTANCES
+                                .iter()
+                                .map(|&distance| {
+                                    view! {
+                                        <option
+                                            value=distance.as_meters().to_string()
+                                            selected=distance == Distance::meters(2000)
+                                        >
+                                            {distance.to_string()}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    {move || {
+                        split_times(split_to_pace(split_get.get()), distance_get.get(), SPLIT_DISTANCE)
+                            .into_iter()
+                            .map(|(i, time)| {
+                                view! {
+                                    <div style="white-space: nowrap; display: inline-block;">
+                                        {format!("{}: {}", i, time)}
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+            </div>
+        </details>
+    }
+}
@@ -21,15 +21,23 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::clock_time::format_clock_time;
+use crate::splits::split_times_with_stops;
+use crate::stops::Stop;
+use crate::units::{Distance, Pace, TimeOfDay};
 use leptos::prelude::*;
-use std::time::Duration;
 
+/// Lists the split times. The splits are shown unless `show_splits_get`
+/// says otherwise; without a start time or stops, clock times and stops
+/// are left out.
 #[component]
 pub fn SplitsList(
-    pace_get: ReadSignal<Duration>,
-    distance_get: ReadSignal<usize>,
-    splits_get: ReadSignal<usize>,
-    show_splits_get: ReadSignal<bool>,
+    pace_get: ReadSignal<Pace>,
+    distance_get: ReadSignal<Distance>,
+    splits_get: ReadSignal<Distance>,
+    #[prop(into, default = Signal::stored(true))] show_splits_get: Signal<bool>,
+    #[prop(into, default = Signal::stored(None))] start_time_get: Signal<Option<TimeOfDay>>,
+    #[prop(into, default = Signal::stored(Vec::new()))] stops_get: Signal<Vec<Stop>>,
 ) -> impl IntoView {
     view! {
         <div style="display: flex; flex-wrap: wrap; gap: 10px; margin-left: 10px; justify-content: flex-start; align-items: baseline;">
@@ -37,30 +45,33 @@ pub fn SplitsList(
                 if show_splits_get.get() {
                     {
                         move || {
-                            let pace = pace_get.get();
-                            let distance = distance_get.get();
-                            let splits = splits_get.get();
-                            if pace > Duration::ZERO && distance > 0 && splits > 0 {
-                                let mut entries = Vec::new();
-                                for i in (splits..=distance as usize).step_by(splits) {
-                                    let split_duration_secs = pace.as_secs_f64()
-                                        * (i as f64 / 1000.0);
-                                    let total_seconds = split_duration_secs as u32;
-                                    let minutes = total_seconds / 60;
-                                    let seconds = total_seconds % 60;
-                                    entries
-                                        .push(
-                                            view! {
-                                                <div style="white-space: nowrap; display: inline-block;">
-                                                    {format!("{}m: {:02}:{:02}", i, minutes, seconds)}
-                                                </div>
-                                            },
-                                        );
-                                }
-                                entries
-                            } else {
-                                Vec::new()
-                            }
+                            let start_time = start_time_get.get();
+                            stops_get
+                                .with(|stops| {
+                                    split_times_with_stops(
+                                        pace_get.get(),
+                                        distance_get.get(),
+                                        splits_get.get(),
+                                        stops,
+                                    )
+                                })
+                                .into_iter()
+                                .map(|(i, time)| {
+                                    let clock_time = start_time
+                                        .map(|start_time| {
+                                            format!(
+                                                " ({})",
+                                                format_clock_time(start_time, time),
+                                            )
+                                        })
+                                        .unwrap_or_default();
+                                    view! {
+                                        <div style="white-space: nowrap; display: inline-block;">
+                                            {format!("{}: {}{}", i, time, clock_time)}
+                                        </div>
+                                    }
+                                })
+                                .collect_view()
                         }
                     }
                         .into_any()
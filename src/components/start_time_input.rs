@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::clock_time::parse_time_of_day;
+use crate::components::FieldError;
+use crate::form_state::FormState;
+use crate::input_text::InputText;
+use crate::units::TimeOfDay;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+#[component]
+pub fn StartTimeInput(
+    id: usize,
+    start_time_get: ReadSignal<Option<TimeOfDay>>,
+    start_time_set: WriteSignal<Option<TimeOfDay>>,
+    error_message_get: ReadSignal<String>,
+    error_message_set: WriteSignal<String>,
+    set_form_states: WriteSignal<HashMap<usize, FormState>>,
+) -> impl IntoView {
+    let text = InputText::new(start_time_get, |start_time| {
+        start_time
+            .map(|start_time| start_time.to_string())
+            .unwrap_or_default()
+    });
+
+    let handle_input = move |ev| {
+        let start_str = event_target_value(&ev);
+        let valid = if start_str.trim().is_empty() {
+            start_time_set.set(None);
+            error_message_set.set(String::new());
+            true
+        } else {
+            match parse_time_of_day(&start_str) {
+                Ok(start_time) => {
+                    start_time_set.set(Some(start_time));
+                    error_message_set.set(String::new());
+                    true
+                }
+                Err(err) => {
+                    error_message_set.set(format!("Start time error: {}", err));
+                    false
+                }
+            }
+        };
+        text.input(start_str, valid);
+        set_form_states.update(|states| {
+            if let Some(state) = states.get_mut(&id) {
+                state.start_time = start_time_get.get();
+            }
+        });
+    };
+
+    let error_id = format!("form-{}-start-time-error", id);
+
+    view! {
+        <label class="contents md:flex md:items-center md:gap-1">
+            <span class="whitespace-nowrap">"Start (hh:mm):"</span>
+            <span class="flex flex-col">
+                <input
+                    type="text"
+                    pattern="[0-9:]+"
+                    placeholder="hh:mm"
+                    class="w-32 px-2 py-1 rounded"
+                    prop:value=move || text.get()
+                    aria-invalid=move || (!error_message_get.get().is_empty()).to_string()
+                    aria-describedby=error_id.clone()
+                    on:input=handle_input
+                    on:focus=move |_| text.focus()
+                    on:blur=move |_| text.blur()
+                />
+                <FieldError id=error_id error_message_get=error_message_get />
+            </span>
+        </label>
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::components::FieldError;
+use crate::form_state::FormState;
+use crate::input_text::InputText;
+use crate::stops::{format_stops, parse_stops, Stop};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+#[component]
+pub fn StopsInput(
+    id: usize,
+    stops_get: ReadSignal<Vec<Stop>>,
+    stops_set: WriteSignal<Vec<Stop>>,
+    error_message_get: ReadSignal<String>,
+    error_message_set: WriteSignal<String>,
+    set_form_states: WriteSignal<HashMap<usize, FormState>>,
+) -> impl IntoView {
+    let text = InputText::new(stops_get, |stops| format_stops(stops));
+
+    let handle_input = move |ev| {
+        let stops_str = event_target_value(&ev);
+        let valid = match parse_stops(&stops_str) {
+            Ok(stops) => {
+                stops_set.set(stops);
+                error_message_set.set(String::new());
+                true
+            }
+            Err(err) => {
+                error_message_set.set(format!("Stops error: {}", err));
+                false
+            }
+        };
+        text.input(stops_str, valid);
+        set_form_states.update(|states| {
+            if let Some(state) = states.get_mut(&id) {
+                state.stops = stops_get.get();
+            }
+        });
+    };
+
+    let error_id = format!("form-{}-stops-error", id);
+
+    view! {
+        <details class="mt-2">
+            <summary class="cursor-pointer">"Planned stops"</summary>
+            <textarea
+                rows="3"
+                placeholder="Distance (m), duration (mm:ss)"
+                class="w-full mt-2 px-2 py-1 rounded"
+                style=format!("color: {}; background-color: {};", colors::WHITE, colors::BLUE4)
+                prop:value=move || text.get()
+                aria-invalid=move || (!error_message_get.get().is_empty()).to_string()
+                aria-describedby=error_id.clone()
+                on:input=handle_input
+                on:focus=move |_| text.focus()
+                on:blur=move |_| text.blur()
+            ></textarea>
+            <FieldError id=error_id error_message_get=error_message_get />
+        </details>
+    }
+}
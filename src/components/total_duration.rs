@@ -21,41 +21,43 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::clock_time::format_clock_time;
+use crate::units::{TimeOfDay, TimeSpan};
 use leptos::prelude::*;
-use std::time::Duration;
 
 #[component]
 pub fn TotalDuration(
-    total_duration: Memo<Option<Duration>>,
-    #[prop(default = false)] is_grid: bool,
+    total_duration: Memo<Option<TimeSpan>>,
+    moving_duration: Memo<Option<TimeSpan>>,
+    start_time_get: ReadSignal<Option<TimeOfDay>>,
 ) -> impl IntoView {
     let formatted_duration = move || {
         total_duration
             .get()
-            .map(|time| {
-                let total_seconds = time.as_secs();
-                let minutes = total_seconds / 60;
-                let seconds = total_seconds % 60;
-                format!("{:02}:{:02}", minutes, seconds)
-            })
+            .map(|time| time.to_string())
             .unwrap_or_else(|| "—".to_string())
     };
+    let formatted_moving = move || match (moving_duration.get(), total_duration.get()) {
+        (Some(moving), Some(time)) if moving != time => {
+            format!(" (moving {})", moving)
+        }
+        _ => String::new(),
+    };
+    let formatted_arrival = move || match (start_time_get.get(), total_duration.get()) {
+        (Some(start_time), Some(time)) => {
+            format!(" (arrival {})", format_clock_time(start_time, time))
+        }
+        _ => String::new(),
+    };
 
-    if is_grid {
-        view! {
-            <div class="flex items-center">
-                <span class="whitespace-nowrap">"Total duration:"</span>
-            </div>
-            <span class="w-32 inline-block">
+    view! {
+        <div class="contents md:flex md:gap-1">
+            <span class="whitespace-nowrap">"Total duration:"</span>
+            <span class="whitespace-nowrap">
                 {formatted_duration}
+                {formatted_moving}
+                {formatted_arrival}
             </span>
-        }.into_any()
-    } else {
-        view! {
-            <div>
-                "Total duration: "
-                {formatted_duration}
-            </div>
-        }.into_any()
+        </div>
     }
 }
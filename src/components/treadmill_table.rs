@@ -0,0 +1,122 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the “Software”), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::colors;
+use crate::treadmill::{outdoor_pace, treadmill_speed, INCLINES};
+use crate::units::{Pace, Speed};
+use leptos::prelude::*;
+
+#[component]
+pub fn TreadmillTable(pace_get: ReadSignal<Pace>) -> impl IntoView {
+    let (speed_get, speed_set) = signal(None::<Speed>);
+    let (incline_get, incline_set) = signal(1.0);
+    let equivalent_pace = move || {
+        speed_get
+            .get()
+            .filter(|speed| speed.as_kmh() > 0.0)
+            .map(|speed| format!("{} /km", outdoor_pace(speed, incline_get.get())))
+            .unwrap_or_else(|| "—".to_string())
+    };
+
+    view! {
+        <details class="mt-2">
+            <summary class="cursor-pointer">"Treadmill"</summary>
+            <table class="mt-2 text-left">
+                <thead>
+                    <tr>
+                        <th class="pr-4">"Incline"</th>
+                        <th class="pr-4">"km/h"</th>
+                        <th class="pr-4">"mph"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        let pace = pace_get.get();
+                        INCLINES
+                            .iter()
+                            .map(|&incline| {
+                                let (kmh, mph) = if !pace.is_zero() {
+                                    let speed = treadmill_speed(pace, incline);
+                                    (
+                                        format!("{:.1}", speed.as_kmh()),
+                                        format!("{:.1}", speed.as_mph()),
+                                    )
+                                } else {
+                                    ("—".to_string(), "—".to_string())
+                                };
+                                view! {
+                                    <tr>
+                                        <td class="pr-4">{format!("{:.1} %", incline)}</td>
+                                        <td class="pr-4">{kmh}</td>
+                                        <td class="pr-4">{mph}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </tbody>
+            </table>
+            <div class="flex flex-wrap gap-5 items-center mt-2">
+                <label>
+                    "Treadmill (km/h): "
+                    <input
+                        type="text"
+                        inputmode="decimal"
+                        class="w-20"
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev).replace(',', ".");
+                            speed_set.set(value.parse::<Speed>().ok());
+                        }
+                    />
+                </label>
+                <label>
+                    "Incline: "
+                    <select
+                        style=format!(
+                            "color: {}; background-color: {}; border-radius: 3px;",
+                            colors::WHITE,
+                            colors::BLUE4,
+                        )
+                        on:change=move |ev| {
+                            if let Ok(incline) = event_target_value(&ev).parse::<f64>() {
+                                incline_set.set(incline);
+                            }
+                        }
+                    >
+                        {INCLINES
+                            .iter()
+                            .map(|&incline| {
+                                view! {
+                                    <option value=incline.to_string() selected=incline == 1.0>
+                                        {format!("{:.1} %", incline)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <div>"Outdoor pace: " {equivalent_pace}</div>
+            </div>
+        </details>
+    }
+}
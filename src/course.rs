@@ -0,0 +1,166 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::clock_time::{parse_hours_minutes, parse_time_of_day};
+use crate::stops::{stop_time_before, Stop};
+use crate::units::{Distance, Pace, TimeOfDay, TimeSpan};
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Planned arrivals closer to the cutoff than this are flagged.
+pub const SAFETY_MARGIN: TimeSpan = TimeSpan::from_secs(15 * 60);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Cutoff {
+    /// Time allowed since the start.
+    Elapsed(TimeSpan),
+    /// Time of day by which the checkpoint must be reached.
+    ClockTime(TimeOfDay),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub name: String,
+    pub distance: Distance,
+    pub cutoff: Cutoff,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CutoffStatus {
+    Safe,
+    Tight,
+    Missed,
+}
+
+impl Cutoff {
+    /// Returns the cutoff as time since the start. Clock time cutoffs
+    /// need a start time and wrap to the next day if they lie before it.
+    pub fn elapsed(&self, start_time: Option<TimeOfDay>) -> Option<TimeSpan> {
+        match self {
+            Cutoff::Elapsed(elapsed) => Some(*elapsed),
+            Cutoff::ClockTime(clock_time) => start_time.map(|start_time| {
+                let start = start_time.as_duration().as_secs();
+                let cutoff = clock_time.as_duration().as_secs();
+                if cutoff >= start {
+                    TimeSpan::from_secs(cutoff - start)
+                } else {
+                    TimeSpan::from_secs(cutoff + SECONDS_PER_DAY - start)
+                }
+            }),
+        }
+    }
+}
+
+impl Checkpoint {
+    /// Returns the margin in seconds between the planned `arrival` and the
+    /// cutoff (negative if the cutoff is missed) and its status.
+    pub fn margin(
+        &self,
+        arrival: TimeSpan,
+        start_time: Option<TimeOfDay>,
+    ) -> Option<(i64, CutoffStatus)> {
+        let cutoff = self.cutoff.elapsed(start_time)?;
+        let margin = cutoff.as_secs() as i64 - arrival.as_secs() as i64;
+        let status = if margin < 0 {
+            CutoffStatus::Missed
+        } else if margin < SAFETY_MARGIN.as_secs() as i64 {
+            CutoffStatus::Tight
+        } else {
+            CutoffStatus::Safe
+        };
+        Some((margin, status))
+    }
+
+    /// Planned time since the start at which the checkpoint is reached,
+    /// including the stops before it.
+    pub fn arrival(&self, pace: Pace, stops: &[Stop]) -> TimeSpan {
+        pace * self.distance + stop_time_before(stops, self.distance)
+    }
+}
+
+fn parse_checkpoint(line: &str) -> Result<Checkpoint, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() != 3 {
+        return Err("Expected name, distance, cutoff".to_string());
+    }
+    if fields[0].is_empty() {
+        return Err("Missing name".to_string());
+    }
+    let distance = fields[1].parse::<Distance>()?;
+    let cutoff = match fields[2].strip_prefix('@') {
+        Some(clock_time) => Cutoff::ClockTime(parse_time_of_day(clock_time)?),
+        None => Cutoff::Elapsed(
+            parse_hours_minutes(fields[2], true)
+                .map_err(|err| format!("Invalid cutoff: {}", err))?
+                .into(),
+        ),
+    };
+    Ok(Checkpoint {
+        name: fields[0].to_string(),
+        distance,
+        cutoff,
+    })
+}
+
+/// Parses a course definition with one checkpoint per line, given as
+/// `name, distance (m), cutoff`. The cutoff is either the time allowed
+/// since the start (`h:mm`) or a time of day prefixed by `@` (`@hh:mm`).
+/// Empty lines and lines starting with `#` are ignored.
+pub fn parse_course(s: &str) -> Result<Vec<Checkpoint>, String> {
+    let mut checkpoints = Vec::new();
+    for (index, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let checkpoint =
+            parse_checkpoint(line).map_err(|err| format!("Line {}: {}", index + 1, err))?;
+        checkpoints.push(checkpoint);
+    }
+    checkpoints.sort_by_key(|checkpoint| checkpoint.distance);
+    Ok(checkpoints)
+}
+
+/// Formats checkpoints in the format accepted by [`parse_course`].
+pub fn format_course(checkpoints: &[Checkpoint]) -> String {
+    checkpoints
+        .iter()
+        .map(|checkpoint| {
+            let (prefix, time) = match checkpoint.cutoff {
+                Cutoff::Elapsed(time) => ("", time),
+                Cutoff::ClockTime(time) => ("@", TimeSpan::from(time.as_duration())),
+            };
+            // Whole minutes are written as `h:mm`.
+            let time = time.format_hms();
+            let cutoff = format!("{}{}", prefix, time.strip_suffix(":00").unwrap_or(&time));
+            format!(
+                "{}, {}, {}",
+                checkpoint.name,
+                checkpoint.distance.as_meters(),
+                cutoff
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
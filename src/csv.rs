@@ -0,0 +1,70 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::form_state::FormState;
+use crate::splits::{split_times, split_times_with_stops};
+use crate::units::{Distance, TimeSpan};
+
+const HEADER: &str = "label,distance (m),lap time,cumulative time,pace (/km)";
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn split_rows(state: &FormState) -> Vec<String> {
+    let label = escape(&state.label);
+    let mut previous = (Distance::ZERO, TimeSpan::ZERO, TimeSpan::ZERO);
+    // The pace of a lap leaves out the stops in it.
+    let moving_times = split_times(state.pace, state.distance, state.splits);
+    split_times_with_stops(state.pace, state.distance, state.splits, &state.stops)
+        .into_iter()
+        .zip(moving_times)
+        .map(|((distance, time), (_, moving_time))| {
+            let lap_time = time - previous.1;
+            let pace = (moving_time - previous.2) / (distance - previous.0);
+            previous = (distance, time, moving_time);
+            format!(
+                "{},{},{},{},{}",
+                label,
+                distance.as_meters(),
+                lap_time.format_hms(),
+                time.format_hms(),
+                TimeSpan::from(pace.time_per_km()).format_hms()
+            )
+        })
+        .collect()
+}
+
+/// Returns the splits of all given forms as CSV with one row per split.
+pub fn splits_csv<'a>(states: impl IntoIterator<Item = &'a FormState>) -> String {
+    let mut lines = vec![HEADER.to_string()];
+    for state in states {
+        lines.extend(split_rows(state));
+    }
+    lines.push(String::new());
+    lines.join("\r\n")
+}
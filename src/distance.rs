@@ -0,0 +1,110 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Reads distances with a unit or as a race name, for example `10k`,
+// `21.1km`, `5mi`, `800yd`, `HM` or `M`.
+
+use crate::units::Distance;
+
+const HALF_MARATHON: Distance = Distance::meters(21097);
+const MARATHON: Distance = Distance::meters(42195);
+
+const METERS_PER_KM: f64 = 1000.0;
+const METERS_PER_MILE: f64 = 1609.344;
+const METERS_PER_YARD: f64 = 0.9144;
+
+const FORMAT_HINT: &str = "use meters or a unit, for example 10km, 5mi or HM";
+
+/// Parses a distance, rounded to whole meters. Without a unit the number
+/// is meters. `M` alone is a marathon, but after a number it could mean
+/// meters or miles and is rejected.
+pub fn parse_distance(s: &str) -> Result<Distance, String> {
+    let s = s.trim();
+    match s.to_lowercase().as_str() {
+        "hm" | "half" | "half marathon" => return Ok(HALF_MARATHON),
+        "marathon" => return Ok(MARATHON),
+        _ if s == "M" => return Ok(MARATHON),
+        _ => {}
+    }
+    let unit_start = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(unit_start);
+    let number: f64 = number
+        .trim()
+        .replace(',', ".")
+        .parse()
+        .map_err(|_| format!("Could not read the distance, {}", FORMAT_HINT))?;
+    let unit = unit.trim();
+    if unit == "M" {
+        return Err(format!(
+            "{} is ambiguous, write {}m for meters or {}mi for miles",
+            s, number, number
+        ));
+    }
+    let meters_per_unit = match unit.to_lowercase().as_str() {
+        "" | "m" | "meters" | "metres" => 1.0,
+        "k" | "km" => METERS_PER_KM,
+        "mi" | "mile" | "miles" => METERS_PER_MILE,
+        "yd" | "yds" | "yards" => METERS_PER_YARD,
+        _ => return Err(format!("Unknown unit {}, {}", unit, FORMAT_HINT)),
+    };
+    Ok(Distance::meters((number * meters_per_unit).round() as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_units_and_race_names() {
+        for (input, meters) in [
+            ("5000", 5000),
+            ("400m", 400),
+            ("10k", 10000),
+            ("5K", 5000),
+            ("21.1km", 21100),
+            ("21,1 km", 21100),
+            ("5mi", 8047),
+            ("800yd", 732),
+            ("HM", 21097),
+            ("half marathon", 21097),
+            ("M", 42195),
+            ("Marathon", 42195),
+        ] {
+            assert_eq!(
+                parse_distance(input),
+                Ok(Distance::meters(meters)),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_ambiguous_and_unknown_distances() {
+        for input in ["5M", "m", "3 furlongs", "x", ""] {
+            assert!(parse_distance(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Encoder for FIT workout files, see the FIT protocol description at
+// https://developer.garmin.com/fit/protocol/. A workout file consists of a
+// file_id, a workout and one workout_step message per step.
+
+use crate::form_state::FormState;
+use crate::splits::split_times;
+use crate::units::{Distance, Pace, TimeSpan};
+use std::time::Duration;
+
+const HEADER_SIZE: u8 = 14;
+const PROTOCOL_VERSION: u8 = 0x10;
+const PROFILE_VERSION: u16 = 2132;
+
+/// Seconds between the Unix epoch and the FIT epoch (1989-12-31 UTC).
+const FIT_EPOCH_OFFSET: u64 = 631_065_600;
+
+const ENUM: u8 = 0x00;
+const STRING: u8 = 0x07;
+const UINT16: u8 = 0x84;
+const UINT32: u8 = 0x86;
+const UINT32Z: u8 = 0x8c;
+
+const FILE_ID: u16 = 0;
+const WORKOUT: u16 = 26;
+const WORKOUT_STEP: u16 = 27;
+
+const FILE_TYPE_WORKOUT: u8 = 5;
+const MANUFACTURER_DEVELOPMENT: u16 = 255;
+const SPORT_RUNNING: u8 = 1;
+
+const DURATION_TIME: u8 = 0;
+const DURATION_DISTANCE: u8 = 1;
+const TARGET_SPEED: u8 = 0;
+const TARGET_OPEN: u8 = 2;
+
+const WORKOUT_NAME_SIZE: u8 = 32;
+const STEP_NAME_SIZE: u8 = 16;
+const INVALID_UINT32: u32 = 0xffff_ffff;
+
+/// Longest distance a step can have, as FIT stores it in centimeters in
+/// 32 bits.
+const MAX_DISTANCE: Distance = Distance::meters(u32::MAX as usize / 100);
+
+/// Most steps a workout can have, as watches reject longer workouts. It
+/// also keeps the step count and index within their 16 bit fields.
+const MAX_STEPS: usize = 50;
+
+/// Allowed deviation from the planned pace in both directions.
+pub const PACE_TOLERANCE: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Intensity {
+    Active = 0,
+    Rest = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepDuration {
+    Distance(Distance),
+    Time(TimeSpan),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkoutStep {
+    pub name: String,
+    pub duration: StepDuration,
+    /// Fastest and slowest pace.
+    pub pace: Option<(Pace, Pace)>,
+    pub intensity: Intensity,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Workout {
+    pub name: String,
+    pub steps: Vec<WorkoutStep>,
+}
+
+impl Workout {
+    /// Creates a running workout with one step per split of the form and
+    /// a rest step for every planned stop. Returns `None` if the form has
+    /// no pace or distance, or if the distance or the number of steps is
+    /// too large for a FIT file.
+    pub fn from_form(state: &FormState) -> Option<Self> {
+        if state.pace.is_zero() || state.distance.is_zero() || state.distance > MAX_DISTANCE {
+            return None;
+        }
+        let pace = (
+            state.pace.saturating_sub(PACE_TOLERANCE),
+            state.pace + PACE_TOLERANCE,
+        );
+        let mut boundaries: Vec<Distance> = split_times(state.pace, state.distance, state.splits)
+            .into_iter()
+            .map(|(distance, _)| distance)
+            .chain(state.stops.iter().map(|stop| stop.distance))
+            .filter(|&distance| !distance.is_zero() && distance <= state.distance)
+            .collect();
+        boundaries.push(state.distance);
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut steps = Vec::new();
+        let mut previous = Distance::ZERO;
+        for distance in boundaries {
+            steps.push(WorkoutStep {
+                name: distance.to_string(),
+                duration: StepDuration::Distance(distance - previous),
+                pace: Some(pace),
+                intensity: Intensity::Active,
+            });
+            for stop in state.stops.iter().filter(|stop| stop.distance == distance) {
+                if distance < state.distance {
+                    steps.push(WorkoutStep {
+                        name: "Stop".to_string(),
+                        duration: StepDuration::Time(stop.duration),
+                        pace: None,
+                        intensity: Intensity::Rest,
+                    });
+                }
+            }
+            previous = distance;
+        }
+        if steps.len() > MAX_STEPS {
+            return None;
+        }
+
+        let name = if state.label.is_empty() {
+            format!("{} @ {}", state.distance, state.pace)
+        } else {
+            state.label.clone()
+        };
+        Some(Self { name, steps })
+    }
+}
+
+/// Converts seconds since the Unix epoch to a FIT timestamp.
+pub fn fit_timestamp(unix_seconds: u64) -> u32 {
+    unix_seconds.saturating_sub(FIT_EPOCH_OFFSET) as u32
+}
+
+/// CRC-16 as used by FIT files.
+fn crc16(crc: u16, bytes: &[u8]) -> u16 {
+    const TABLE: [u16; 16] = [
+        0x0000, 0xcc01, 0xd801, 0x1400, 0xf001, 0x3c00, 0x2800, 0xe401, 0xa001, 0x6c00, 0x7800,
+        0xb401, 0x5000, 0x9c01, 0x8801, 0x4400,
+    ];
+    bytes.iter().fold(crc, |crc, &byte| {
+        let tmp = TABLE[(crc & 0xf) as usize];
+        let crc = ((crc >> 4) & 0x0fff) ^ tmp ^ TABLE[(byte & 0xf) as usize];
+        let tmp = TABLE[(crc & 0xf) as usize];
+        ((crc >> 4) & 0x0fff) ^ tmp ^ TABLE[(byte >> 4) as usize]
+    })
+}
+
+/// Speed in mm/s as used by FIT speed targets.
+fn speed(pace: Pace) -> u32 {
+    (1_000_000.0 / pace.time_per_km().as_secs_f64()).round() as u32
+}
+
+struct Encoder {
+    data: Vec<u8>,
+}
+
+impl Encoder {
+    fn define(&mut self, local: u8, global: u16, fields: &[(u8, u8, u8)]) {
+        self.data.push(0x40 | local);
+        self.data.push(0);
+        self.data.push(0);
+        self.u16(global);
+        self.data.push(fields.len() as u8);
+        for &(number, size, base_type) in fields {
+            self.data.extend([number, size, base_type]);
+        }
+    }
+
+    fn record(&mut self, local: u8) {
+        self.data.push(local);
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.data.extend(value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.data.extend(value.to_le_bytes());
+    }
+
+    /// Writes a null terminated string padded to `size` bytes.
+    fn string(&mut self, value: &str, size: u8) {
+        let mut end = value.len().min(size as usize - 1);
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.data.extend(&value.as_bytes()[..end]);
+        self.data.resize(self.data.len() + size as usize - end, 0);
+    }
+}
+
+/// Encodes a workout as FIT file created at the FIT timestamp
+/// `time_created`.
+pub fn encode_workout(workout: &Workout, time_created: u32) -> Vec<u8> {
+    let mut encoder = Encoder { data: Vec::new() };
+
+    encoder.define(
+        0,
+        FILE_ID,
+        &[
+            (0, 1, ENUM),
+            (1, 2, UINT16),
+            (2, 2, UINT16),
+            (3, 4, UINT32Z),
+            (4, 4, UINT32),
+        ],
+    );
+    encoder.record(0);
+    encoder.u8(FILE_TYPE_WORKOUT);
+    encoder.u16(MANUFACTURER_DEVELOPMENT);
+    encoder.u16(0);
+    encoder.u32(1);
+    encoder.u32(time_created);
+
+    encoder.define(
+        1,
+        WORKOUT,
+        &[(8, WORKOUT_NAME_SIZE, STRING), (4, 1, ENUM), (6, 2, UINT16)],
+    );
+    encoder.record(1);
+    encoder.string(&workout.name, WORKOUT_NAME_SIZE);
+    encoder.u8(SPORT_RUNNING);
+    encoder.u16(workout.steps.len() as u16);
+
+    encoder.define(
+        2,
+        WORKOUT_STEP,
+        &[
+            (254, 2, UINT16),
+            (0, STEP_NAME_SIZE, STRING),
+            (1, 1, ENUM),
+            (2, 4, UINT32),
+            (3, 1, ENUM),
+            (4, 4, UINT32),
+            (5, 4, UINT32),
+            (6, 4, UINT32),
+            (7, 1, ENUM),
+        ],
+    );
+    for (index, step) in workout.steps.iter().enumerate() {
+        encoder.record(2);
+        encoder.u16(index as u16);
+        encoder.string(&step.name, STEP_NAME_SIZE);
+        let (duration_type, duration_value) = match step.duration {
+            StepDuration::Distance(distance) => {
+                let centimeters = u32::try_from(distance.as_meters())
+                    .unwrap_or(u32::MAX)
+                    .saturating_mul(100);
+                (DURATION_DISTANCE, centimeters)
+            }
+            StepDuration::Time(time) => {
+                let milliseconds =
+                    u32::try_from(time.as_duration().as_millis()).unwrap_or(u32::MAX);
+                (DURATION_TIME, milliseconds)
+            }
+        };
+        encoder.u8(duration_type);
+        encoder.u32(duration_value);
+        match step.pace {
+            Some((fastest, slowest)) => {
+                encoder.u8(TARGET_SPEED);
+                encoder.u32(0);
+                encoder.u32(speed(slowest));
+                encoder.u32(speed(fastest));
+            }
+            None => {
+                encoder.u8(TARGET_OPEN);
+                encoder.u32(0);
+                encoder.u32(INVALID_UINT32);
+                encoder.u32(INVALID_UINT32);
+            }
+        }
+        encoder.u8(step.intensity as u8);
+    }
+
+    let mut file = vec![HEADER_SIZE, PROTOCOL_VERSION];
+    file.extend(PROFILE_VERSION.to_le_bytes());
+    file.extend((encoder.data.len() as u32).to_le_bytes());
+    file.extend(b".FIT");
+    file.extend(crc16(0, &file).to_le_bytes());
+    file.extend(encoder.data);
+    file.extend(crc16(0, &file).to_le_bytes());
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stops::Stop;
+
+    type Message = (u16, Vec<(u8, Vec<u8>)>);
+
+    /// Decodes the messages of a FIT file into their global message number
+    /// and field values, checking header and CRCs on the way.
+    fn decode(file: &[u8]) -> Vec<Message> {
+        assert_eq!(file[0], HEADER_SIZE);
+        assert_eq!(&file[8..12], b".FIT");
+        assert_eq!(crc16(0, &file[..14]), 0);
+        assert_eq!(crc16(0, file), 0);
+        let data_size = u32::from_le_bytes(file[4..8].try_into().unwrap()) as usize;
+        assert_eq!(file.len(), 14 + data_size + 2);
+
+        let mut definitions = std::collections::HashMap::new();
+        let mut messages = Vec::new();
+        let mut data = &file[14..14 + data_size];
+        while !data.is_empty() {
+            let header = data[0];
+            let local = header & 0x0f;
+            if header & 0x40 != 0 {
+                let global = u16::from_le_bytes([data[3], data[4]]);
+                let count = data[5] as usize;
+                let fields: Vec<(u8, usize)> = data[6..6 + 3 * count]
+                    .chunks(3)
+                    .map(|field| (field[0], field[1] as usize))
+                    .collect();
+                definitions.insert(local, (global, fields));
+                data = &data[6 + 3 * count..];
+            } else {
+                let (global, fields) = &definitions[&local];
+                data = &data[1..];
+                let mut values = Vec::new();
+                for &(number, size) in fields {
+                    values.push((number, data[..size].to_vec()));
+                    data = &data[size..];
+                }
+                messages.push((*global, values));
+            }
+        }
+        messages
+    }
+
+    fn field(message: &Message, number: u8) -> &[u8] {
+        &message.1.iter().find(|(n, _)| *n == number).unwrap().1
+    }
+
+    fn u32_field(message: &Message, number: u8) -> u32 {
+        u32::from_le_bytes(field(message, number).try_into().unwrap())
+    }
+
+    #[test]
+    fn crc_matches_check_value() {
+        assert_eq!(crc16(0, b"123456789"), 0xbb3d);
+    }
+
+    // The expected bytes were laid out by hand from the protocol
+    // description, not produced by the FIT SDK.
+    #[test]
+    fn encodes_byte_layout_of_protocol_description() {
+        let workout = Workout {
+            name: "Easy".to_string(),
+            steps: vec![WorkoutStep {
+                name: "1000m".to_string(),
+                duration: StepDuration::Distance(Distance::meters(1000)),
+                pace: Some((
+                    Pace::per_km(Duration::from_secs(295)),
+                    Pace::per_km(Duration::from_secs(305)),
+                )),
+                intensity: Intensity::Active,
+            }],
+        };
+
+        let mut expected = vec![
+            // file_id definition and data
+            0x40, 0, 0, 0, 0, 5, 0, 1, 0x00, 1, 2, 0x84, 2, 2, 0x84, 3, 4, 0x8c, 4, 4, 0x86, 0x00,
+            5, 0xff, 0x00, 0, 0, 1, 0, 0, 0, 0x00, 0xca, 0x9a, 0x3b,
+            // workout definition
+            0x41, 0, 0, 26, 0, 3, 8, 32, 0x07, 4, 1, 0x00, 6, 2, 0x84,
+        ];
+        expected.push(0x01);
+        expected.extend(b"Easy");
+        expected.extend([0; 28]);
+        expected.extend([1, 1, 0]);
+        expected.extend([
+            // workout_step definition
+            0x42, 0, 0, 27, 0, 9, 254, 2, 0x84, 0, 16, 0x07, 1, 1, 0x00, 2, 4, 0x86, 3, 1, 0x00, 4,
+            4, 0x86, 5, 4, 0x86, 6, 4, 0x86, 7, 1, 0x00,
+        ]);
+        expected.extend([0x02, 0, 0]);
+        expected.extend(b"1000m");
+        expected.extend([0; 11]);
+        expected.extend([
+            1, 0xa0, 0x86, 0x01, 0x00, 0, 0, 0, 0, 0, 0xcf, 0x0c, 0, 0, 0x3e, 0x0d, 0, 0, 0,
+        ]);
+
+        let file = encode_workout(&workout, 1_000_000_000);
+        assert_eq!(&file[..4], &[14, 0x10, 0x54, 0x08]);
+        assert_eq!(&file[4..8], &(expected.len() as u32).to_le_bytes());
+        assert_eq!(&file[14..file.len() - 2], expected.as_slice());
+        assert_eq!(crc16(0, &file), 0);
+    }
+
+    #[test]
+    fn round_trips_form_with_stops() {
+        let state = FormState {
+            pace: Pace::per_km(Duration::from_secs(300)),
+            splits: Distance::meters(1000),
+            distance: Distance::meters(2500),
+            label: "Long run with a very long name".to_string(),
+            stops: vec![Stop {
+                distance: Distance::meters(1500),
+                duration: TimeSpan::from(Duration::from_secs(30)),
+            }],
+            ..FormState::default()
+        };
+        let workout = Workout::from_form(&state).unwrap();
+        let messages = decode(&encode_workout(&workout, fit_timestamp(1_700_000_000)));
+
+        assert_eq!(messages.len(), 2 + 5);
+        assert_eq!(messages[0].0, FILE_ID);
+        assert_eq!(field(&messages[0], 0), &[FILE_TYPE_WORKOUT]);
+        assert_eq!(u32_field(&messages[0], 4), 1_700_000_000 - 631_065_600);
+
+        assert_eq!(messages[1].0, WORKOUT);
+        assert_eq!(
+            &field(&messages[1], 8)[..31],
+            b"Long run with a very long name\0"
+        );
+        assert_eq!(field(&messages[1], 6), &[5, 0]);
+
+        let steps: Vec<(u8, u32, u8)> = messages[2..]
+            .iter()
+            .map(|step| (field(step, 1)[0], u32_field(step, 2), field(step, 7)[0]))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (DURATION_DISTANCE, 100_000, Intensity::Active as u8),
+                (DURATION_DISTANCE, 50_000, Intensity::Active as u8),
+                (DURATION_TIME, 30_000, Intensity::Rest as u8),
+                (DURATION_DISTANCE, 50_000, Intensity::Active as u8),
+                (DURATION_DISTANCE, 50_000, Intensity::Active as u8),
+            ]
+        );
+        assert_eq!(
+            u32_field(&messages[2], 5),
+            speed(Pace::per_km(Duration::from_secs(305)))
+        );
+        assert_eq!(
+            u32_field(&messages[2], 6),
+            speed(Pace::per_km(Duration::from_secs(295)))
+        );
+        assert_eq!(field(&messages[4], 3), &[TARGET_OPEN]);
+    }
+
+    #[test]
+    fn rejects_more_steps_than_watches_accept() {
+        let mut state = FormState {
+            pace: Pace::per_km(Duration::from_secs(300)),
+            splits: Distance::meters(1000),
+            distance: Distance::meters(MAX_STEPS * 1000),
+            ..FormState::default()
+        };
+        assert_eq!(Workout::from_form(&state).unwrap().steps.len(), MAX_STEPS);
+        state.distance = Distance::meters(MAX_STEPS * 1000 + 1);
+        assert_eq!(Workout::from_form(&state), None);
+        state.splits = Distance::meters(1);
+        state.distance = Distance::meters(70_000);
+        assert_eq!(Workout::from_form(&state), None);
+    }
+
+    #[test]
+    fn rejects_distance_beyond_fit_range() {
+        let state = FormState {
+            pace: Pace::per_km(Duration::from_secs(300)),
+            distance: Distance::meters(50_000_000),
+            ..FormState::default()
+        };
+        assert_eq!(Workout::from_form(&state), None);
+    }
+}
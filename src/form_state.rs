@@ -21,25 +21,35 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
 // OTHER DEALINGS IN THE SOFTWARE.
 
-use std::time::Duration;
+use crate::course::Checkpoint;
+use crate::stops::Stop;
+use crate::units::{Distance, Pace, TimeOfDay};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FormState {
-    pub pace: Duration,
-    pub splits: usize,
-    pub distance: usize,
+    pub pace: Pace,
+    pub splits: Distance,
+    pub distance: Distance,
     pub show_splits: bool,
     pub label: String,
+    pub start_time: Option<TimeOfDay>,
+    pub course: Vec<Checkpoint>,
+    pub stops: Vec<Stop>,
 }
 
 impl Default for FormState {
     fn default() -> Self {
         Self {
-            pace: Duration::ZERO,
-            splits: 0,
-            distance: 0,
+            pace: Pace::ZERO,
+            splits: Distance::ZERO,
+            distance: Distance::ZERO,
             show_splits: true,
             label: String::new(),
+            start_time: None,
+            course: Vec::new(),
+            stops: Vec::new(),
         }
     }
 }
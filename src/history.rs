@@ -0,0 +1,151 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+/// Maximum number of undo steps kept.
+const LIMIT: usize = 100;
+
+/// Changes following each other within this many milliseconds are merged
+/// into one undo step, so that typing a pace is undone at once.
+const MERGE_WINDOW: f64 = 1000.0;
+
+/// Undo/redo history of snapshots of type `T`.
+pub struct History<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    current: T,
+    last_change: f64,
+}
+
+impl<T: Clone + PartialEq> History<T> {
+    pub fn new(current: T) -> Self {
+        History {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            current,
+            last_change: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Records `state` at time `now` (in milliseconds). If `mergeable` is
+    /// set and the previous change was recent, both become one step.
+    pub fn record(&mut self, state: T, mergeable: bool, now: f64) {
+        if state == self.current {
+            return;
+        }
+        if mergeable && self.can_undo() && now - self.last_change < MERGE_WINDOW {
+            self.current = state;
+        } else {
+            self.undo.push(std::mem::replace(&mut self.current, state));
+            if self.undo.len() > LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_change = now;
+    }
+
+    /// Steps back and returns the state to restore.
+    pub fn undo(&mut self) -> Option<T> {
+        let state = self.undo.pop()?;
+        self.redo.push(std::mem::replace(&mut self.current, state));
+        self.last_change = f64::NEG_INFINITY;
+        Some(self.current.clone())
+    }
+
+    /// Steps forward again and returns the state to restore.
+    pub fn redo(&mut self) -> Option<T> {
+        let state = self.redo.pop()?;
+        self.undo.push(std::mem::replace(&mut self.current, state));
+        self.last_change = f64::NEG_INFINITY;
+        Some(self.current.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undoes_and_redoes_changes() {
+        let mut history = History::new(0);
+        history.record(1, false, 0.0);
+        history.record(2, false, 2000.0);
+        assert_eq!(history.undo(), Some(1));
+        assert_eq!(history.undo(), Some(0));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(1));
+        assert_eq!(history.redo(), Some(2));
+        assert_eq!(history.redo(), None);
+        assert_eq!(*history.current(), 2);
+    }
+
+    #[test]
+    fn merges_quick_changes() {
+        let mut history = History::new(0);
+        history.record(1, true, 0.0);
+        history.record(2, true, 500.0);
+        history.record(3, false, 700.0);
+        assert_eq!(history.undo(), Some(2));
+        assert_eq!(history.undo(), Some(0));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn new_change_drops_redo_branch() {
+        let mut history = History::new(0);
+        history.record(1, false, 0.0);
+        history.record(2, false, 2000.0);
+        history.undo();
+        history.record(3, false, 4000.0);
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(1));
+        assert_eq!(history.redo(), Some(3));
+    }
+
+    #[test]
+    fn ignores_unchanged_state_and_keeps_limit() {
+        let mut history = History::new(0);
+        history.record(0, false, 0.0);
+        assert!(!history.can_undo());
+        for state in 1..=LIMIT + 10 {
+            history.record(state, false, state as f64 * 2000.0);
+        }
+        let mut steps = 0;
+        while history.undo().is_some() {
+            steps += 1;
+        }
+        assert_eq!(steps, LIMIT);
+    }
+}
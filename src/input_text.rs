@@ -0,0 +1,93 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use leptos::prelude::*;
+
+/// The text of an input bound to a value. While the input has focus it
+/// keeps what the user typed; otherwise, and on blur if the text was
+/// valid, it shows the formatted value.
+#[derive(Clone, Copy)]
+pub struct InputText {
+    text: RwSignal<String>,
+    focused: RwSignal<bool>,
+    valid: RwSignal<bool>,
+    formatted: Memo<String>,
+}
+
+impl InputText {
+    pub fn new<T>(value: ReadSignal<T>, format: fn(&T) -> String) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let formatted = Memo::new(move |_| value.with(format));
+        let text = RwSignal::new(formatted.get_untracked());
+        let focused = RwSignal::new(false);
+        Effect::new(move |_| {
+            let formatted = formatted.get();
+            if !focused.get_untracked() {
+                text.set(formatted);
+            }
+        });
+        InputText {
+            text,
+            focused,
+            valid: RwSignal::new(true),
+            formatted,
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.text.get()
+    }
+
+    /// Keeps the raw text typed by the user and whether it could be parsed.
+    pub fn input(self, raw: String, valid: bool) {
+        self.text.set(raw);
+        self.valid.set(valid);
+    }
+
+    /// The formatted value while the user is typing text that was
+    /// understood but is written differently, so they can check how it
+    /// was read.
+    pub fn interpreted(self) -> Option<String> {
+        let text = self.text.get();
+        let formatted = self.formatted.get();
+        (self.focused.get()
+            && self.valid.get()
+            && !text.trim().is_empty()
+            && !formatted.is_empty()
+            && text.trim() != formatted)
+            .then_some(formatted)
+    }
+
+    pub fn focus(self) {
+        self.focused.set(true);
+    }
+
+    pub fn blur(self) {
+        self.focused.set(false);
+        if self.valid.get_untracked() {
+            self.text.set(self.formatted.get_untracked());
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::plan::Plan;
+use serde::{Deserialize, Serialize};
+
+/// A plan saved under a name. The plan is kept as JSON value so that it
+/// is migrated by [`Plan::from_value`] when opened.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedPlan {
+    pub name: String,
+    plan: serde_json::Value,
+}
+
+impl SavedPlan {
+    pub fn plan(&self) -> Result<Plan, String> {
+        Plan::from_value(self.plan.clone())
+    }
+}
+
+/// The named plans stored in the browser, in the order they were saved.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Library {
+    pub plans: Vec<SavedPlan>,
+}
+
+fn validate_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        Err("Plan name must not be empty".to_string())
+    } else {
+        Ok(name)
+    }
+}
+
+impl Library {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s).map_err(|err| format!("Invalid plan library: {}", err))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.plans.iter().position(|saved| saved.name == name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SavedPlan> {
+        self.plans.iter().find(|saved| saved.name == name)
+    }
+
+    /// Saves `plan` under `name`, replacing a plan of the same name, so
+    /// callers ask before overwriting. Returns the trimmed name.
+    pub fn save(&mut self, name: &str, plan: &Plan) -> Result<String, String> {
+        let name = validate_name(name)?.to_string();
+        let saved = SavedPlan {
+            name: name.clone(),
+            plan: serde_json::to_value(plan).map_err(|err| err.to_string())?,
+        };
+        match self.position(&name) {
+            Some(index) => self.plans[index] = saved,
+            None => self.plans.push(saved),
+        }
+        Ok(name)
+    }
+
+    pub fn rename(&mut self, name: &str, new_name: &str) -> Result<String, String> {
+        let new_name = validate_name(new_name)?.to_string();
+        let index = self
+            .position(name)
+            .ok_or_else(|| format!("There is no plan named {}", name))?;
+        if new_name != name && self.position(&new_name).is_some() {
+            return Err(format!("There already is a plan named {}", new_name));
+        }
+        self.plans[index].name = new_name.clone();
+        Ok(new_name)
+    }
+
+    /// Copies the plan `name` to the first free name of the form
+    /// "name (copy)", "name (copy 2)", … directly after the original.
+    pub fn duplicate(&mut self, name: &str) -> Result<String, String> {
+        let index = self
+            .position(name)
+            .ok_or_else(|| format!("There is no plan named {}", name))?;
+        let new_name = (1..)
+            .map(|n| {
+                if n == 1 {
+                    format!("{} (copy)", name)
+                } else {
+                    format!("{} (copy {})", name, n)
+                }
+            })
+            .find(|candidate| self.position(candidate).is_none())
+            .unwrap_or_default();
+        let copy = SavedPlan {
+            name: new_name.clone(),
+            plan: self.plans[index].plan.clone(),
+        };
+        self.plans.insert(index + 1, copy);
+        Ok(new_name)
+    }
+
+    pub fn delete(&mut self, name: &str) {
+        self.plans.retain(|saved| saved.name != name);
+    }
+}
@@ -22,28 +22,177 @@
 // OTHER DEALINGS IN THE SOFTWARE.
 
 use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
 use leptos::*;
 use std::collections::HashMap;
+use std::time::Duration;
 
+mod activity;
+mod clock_time;
 mod colors;
 mod components;
+mod course;
+mod csv;
+mod distance;
+mod duration;
+mod files;
+mod fit;
 mod form_state;
+mod history;
+mod input_text;
+mod library;
+mod multisport;
+mod pace;
+mod pace_band;
+mod plan;
+mod rowing;
+mod share;
+mod splits;
+mod stops;
+mod storage;
+mod treadmill;
+mod units;
 
 use components::*;
+use csv::splits_csv;
+use files::{download, read_text, take_selected_file};
 use form_state::FormState;
+use history::History;
+use plan::Plan;
+use share::{decode_share_fragment, encode_share_fragment};
+use storage::{load_draft, load_forms, save_draft, save_forms};
 
 const TITLE: &str = "Pace calculator";
 
+/// The form order and the states of all forms.
+type Snapshot = (Vec<usize>, HashMap<usize, FormState>);
+
+/// Returns the plan to start with when a share link is opened. Forms the
+/// user already has are kept as draft, after asking, so that the shared
+/// plan does not overwrite them.
+fn open_shared_plan(plan: Plan) -> Option<Plan> {
+    let Some(current) = load_forms() else {
+        return Some(plan);
+    };
+    let empty = current
+        .forms
+        .iter()
+        .all(|form| form.state == FormState::default());
+    if empty || current == plan {
+        return Some(plan);
+    }
+    let question = if load_draft().is_some() {
+        "Open the shared plan? Your current forms replace the earlier draft in Saved plans."
+    } else {
+        "Open the shared plan? Your current forms are kept as draft in Saved plans."
+    };
+    if window().confirm_with_message(question).unwrap_or(false) {
+        save_draft(&current);
+        Some(plan)
+    } else {
+        Some(current)
+    }
+}
+
 #[component]
 fn App() -> impl IntoView {
     let _ = document().set_title(TITLE);
 
-    let (forms_get, forms_set) = signal(vec![0]);
-    let (next_id_get, next_id_set) = signal(1);
-    let (form_states_get, form_states_set) = signal(HashMap::<usize, FormState>::new());
+    let (message_get, message_set) = signal(String::new());
+    let (link_copied_get, link_copied_set) = signal(false);
+
+    let initial_plan = match location_hash().and_then(|hash| decode_share_fragment(&hash)) {
+        Some(shared_plan) => {
+            if let Ok(history) = window().history() {
+                let url = format!(
+                    "{}{}",
+                    location_pathname().unwrap_or_default(),
+                    window().location().search().unwrap_or_default(),
+                );
+                let _ =
+                    history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url));
+            }
+            match shared_plan {
+                Ok(plan) => open_shared_plan(plan),
+                Err(err) => {
+                    message_set.set(err);
+                    load_forms()
+                }
+            }
+        }
+        None => load_forms(),
+    };
+    let (initial_forms, initial_states) = initial_plan
+        .map(Plan::into_forms)
+        .filter(|(forms, _)| !forms.is_empty())
+        .unwrap_or_else(|| (vec![0], HashMap::from([(0, FormState::default())])));
+    let initial_next_id = initial_forms.iter().max().map_or(0, |id| id + 1);
+
+    let (forms_get, forms_set) = signal(initial_forms);
+    let (next_id_get, next_id_set) = signal(initial_next_id);
+    let (form_states_get, form_states_set) = signal(initial_states);
+
+    Effect::new(move |_| {
+        forms_get.with(|forms| {
+            form_states_get.with(|states| save_forms(&Plan::new(forms, states)));
+        });
+    });
+
+    let (history_get, history_set) = signal(History::<Snapshot>::new((
+        forms_get.get_untracked(),
+        form_states_get.get_untracked(),
+    )));
+
+    Effect::new(move |_| {
+        let snapshot = (forms_get.get(), form_states_get.get());
+        history_set.update(|history| {
+            let mergeable = history.current().0 == snapshot.0;
+            history.record(snapshot, mergeable, js_sys::Date::now());
+        });
+    });
+
+    let restore = move |(forms, states): Snapshot| {
+        // A loaded plan may have lowered the next id below the restored ones.
+        let min_next_id = forms.iter().max().map_or(0, |id| id + 1);
+        next_id_set.update(|id| *id = (*id).max(min_next_id));
+        form_states_set.set(states);
+        forms_set.set(forms);
+    };
+    let undo = move || {
+        if let Some(snapshot) = history_set.try_update(|history| history.undo()).flatten() {
+            restore(snapshot);
+        }
+    };
+    let redo = move || {
+        if let Some(snapshot) = history_set.try_update(|history| history.redo()).flatten() {
+            restore(snapshot);
+        }
+    };
 
-    form_states_set.update(|states| {
-        states.insert(0, FormState::default());
+    let _ = window_event_listener(ev::keydown, move |ev| {
+        // Text fields keep their own undo, and a focused input would not
+        // show the restored value anyway.
+        let editing = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .and_then(|element| {
+                element
+                    .closest("input, textarea, select, [contenteditable]")
+                    .ok()
+                    .flatten()
+            })
+            .is_some();
+        if editing {
+            return;
+        }
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case("z") {
+            ev.prevent_default();
+            if ev.shift_key() {
+                redo();
+            } else {
+                undo();
+            }
+        }
     });
 
     let add_form = move |_| {
@@ -57,6 +206,61 @@ fn App() -> impl IntoView {
         next_id_set.update(|id| *id += 1);
     };
 
+    let load_plan = Callback::new(move |plan: Plan| {
+        let (forms, states) = plan.into_forms();
+        if forms.is_empty() {
+            message_set.set("The plan does not contain any forms.".to_string());
+            return;
+        }
+        next_id_set.set(forms.iter().max().map_or(0, |id| id + 1));
+        form_states_set.set(states);
+        forms_set.set(forms);
+        message_set.set(String::new());
+    });
+
+    let export_plan = move |_| {
+        let json = forms_get
+            .with(|forms| form_states_get.with(|states| Plan::new(forms, states).to_json_pretty()));
+        download("pace-plan.json", "application/json", json.as_bytes());
+    };
+
+    let export_csv = move |_| {
+        let csv = forms_get.with(|forms| {
+            form_states_get.with(|states| splits_csv(forms.iter().filter_map(|id| states.get(id))))
+        });
+        download("splits.csv", "text/csv", csv.as_bytes());
+    };
+
+    let import_plan = move |ev: web_sys::Event| {
+        if let Some(file) = take_selected_file(&ev) {
+            leptos::task::spawn_local(async move {
+                match read_text(file)
+                    .await
+                    .and_then(|json| Plan::from_json(&json))
+                {
+                    Ok(plan) => load_plan.run(plan),
+                    Err(err) => message_set.set(err),
+                }
+            });
+        }
+    };
+
+    let copy_share_link = move |_| {
+        let fragment = forms_get.with(|forms| {
+            form_states_get.with(|states| encode_share_fragment(&Plan::new(forms, states)))
+        });
+        let location = location();
+        let url = format!(
+            "{}{}#{}",
+            location.origin().unwrap_or_default(),
+            location.pathname().unwrap_or_default(),
+            fragment,
+        );
+        let _ = window().navigator().clipboard().write_text(&url);
+        link_copied_set.set(true);
+        set_timeout(move || link_copied_set.set(false), Duration::from_secs(2));
+    };
+
     let delete_form = Callback::new(move |id: usize| {
         forms_set.update(|forms| {
             if let Some(pos) = forms.iter().position(|&form_id| form_id == id) {
@@ -68,6 +272,51 @@ fn App() -> impl IntoView {
         });
     });
 
+    let duplicate_form = Callback::new(move |id: usize| {
+        let new_id = next_id_get.get();
+        let state = form_states_get.with(|states| states.get(&id).cloned().unwrap_or_default());
+        form_states_set.update(|states| {
+            states.insert(new_id, state);
+        });
+        forms_set.update(|forms| {
+            let pos = forms
+                .iter()
+                .position(|&form_id| form_id == id)
+                .map_or(forms.len(), |pos| pos + 1);
+            forms.insert(pos, new_id);
+        });
+        next_id_set.update(|id| *id += 1);
+    });
+
+    // Moves the form at `from` to `to` and focuses its drag handle again,
+    // which is lost when its element is moved in the DOM.
+    let move_form = Callback::new(move |(from, to): (usize, usize)| {
+        let count = forms_get.with_untracked(Vec::len);
+        if from == to || from >= count || to >= count {
+            return;
+        }
+        forms_set.update(|forms| {
+            let id = forms.remove(from);
+            forms.insert(to, id);
+        });
+        request_animation_frame(move || {
+            let selector = format!("[data-form-index='{}'] [data-drag-handle]", to);
+            if let Some(handle) = document()
+                .query_selector(&selector)
+                .ok()
+                .flatten()
+                .and_then(|handle| handle.dyn_into::<web_sys::HtmlElement>().ok())
+            {
+                let _ = handle.focus();
+            }
+        });
+    });
+
+    let button_style = format!(
+        "background-color:{}; color: white; border: none; padding: 6px 12px; border-radius: 5px; cursor: pointer;",
+        colors::BLUE4,
+    );
+
     view! {
         <>
             <style>
@@ -85,6 +334,15 @@ fn App() -> impl IntoView {
 
                 "/* Ensures cursor visibility on iOS */"
                 "input { -webkit-appearance: none; -moz-appearance: none; appearance: none; caret-color: #000; }"
+
+                "button:disabled { opacity: 0.5; cursor: default; }"
+                {format!(
+                    "input[aria-invalid='true'], textarea[aria-invalid='true'] {{ outline: 2px solid {}; }}",
+                    colors::RED,
+                )}
+
+                "/* Prints only the pace table while its print button is used */"
+                "@media print { .printing-pace-table body * { visibility: hidden; } .printing-pace-table .pace-table, .printing-pace-table .pace-table * { visibility: visible; color: #000; } .printing-pace-table .pace-table { position: absolute; left: 0; top: 0; } .printing-pace-table .pace-table table { border-collapse: collapse; font-size: 10pt; } .printing-pace-table .pace-table th, .printing-pace-table .pace-table td { border: 1px solid #999; padding: 1px 4px; } }"
             </style>
             <div style="padding: 10px;">
                 <div style="display: flex; align-items: center; justify-content: space-between; gap: 10px;">
@@ -97,36 +355,37 @@ fn App() -> impl IntoView {
                         />
                     </a>
                 </div>
+                <ErrorMessage error_message_get=message_get />
                 <div>
-                    {move || {
-                        forms_get
-                            .get()
-                            .into_iter()
-                            .enumerate()
-                            .map(|(index, id)| {
-                                let delete_option = if index > 0 {
-                                    Some(delete_form.clone())
-                                } else {
-                                    None
-                                };
-                                let form_state = Memo::new(move |_| {
-                                    form_states_get
-                                        .with(|states| states.get(&id).cloned().unwrap_or_default())
-                                });
-
-                                view! {
-                                    <PaceCalculatorForm
-                                        id=id
-                                        on_delete=delete_option
-                                        form_state=form_state
-                                        set_form_states=form_states_set.clone()
-                                    />
-                                }
-                            })
-                            .collect_view()
-                    }}
+                    <For
+                        each=move || forms_get.get()
+                        key=|id| *id
+                        children=move |id| {
+                            let index = Memo::new(move |_| {
+                                forms_get
+                                    .with(|forms| forms.iter().position(|&form_id| form_id == id))
+                                    .unwrap_or_default()
+                            });
+                            let form_state = Memo::new(move |_| {
+                                form_states_get
+                                    .with(|states| states.get(&id).cloned().unwrap_or_default())
+                            });
+
+                            view! {
+                                <PaceCalculatorForm
+                                    id=id
+                                    index=index
+                                    on_move=move_form
+                                    on_delete=delete_form
+                                    on_duplicate=duplicate_form
+                                    form_state=form_state
+                                    set_form_states=form_states_set.clone()
+                                />
+                            }
+                        }
+                    />
                 </div>
-                <div style="margin-top: 15px;">
+                <div style="margin-top: 15px; display: flex; flex-wrap: wrap; gap: 10px; align-items: center;">
                     <button
                         on:click=add_form
                         style=format!(
@@ -136,7 +395,59 @@ fn App() -> impl IntoView {
                     >
                         "+"
                     </button>
+                    <button
+                        on:click=move |_| undo()
+                        disabled=move || history_get.with(|history| !history.can_undo())
+                        title="Undo (Ctrl+Z)"
+                        style=button_style.clone()
+                    >
+                        "Undo"
+                    </button>
+                    <button
+                        on:click=move |_| redo()
+                        disabled=move || history_get.with(|history| !history.can_redo())
+                        title="Redo (Ctrl+Shift+Z)"
+                        style=button_style.clone()
+                    >
+                        "Redo"
+                    </button>
+                    <button
+                        on:click=copy_share_link
+                        style=button_style.clone()
+                    >
+                        {move || if link_copied_get.get() { "Link copied" } else { "Copy share link" }}
+                    </button>
+                    <button
+                        on:click=export_plan
+                        style=button_style.clone()
+                    >
+                        "Export JSON"
+                    </button>
+                    <button
+                        on:click=export_csv
+                        style=button_style.clone()
+                    >
+                        "Export CSV"
+                    </button>
+                    <label style=button_style>
+                        "Import JSON"
+                        <input
+                            type="file"
+                            accept=".json,application/json"
+                            style="display: none;"
+                            on:change=import_plan
+                        />
+                    </label>
                 </div>
+                <PlanLibrary
+                    forms_get=forms_get
+                    form_states_get=form_states_get
+                    load_plan=load_plan
+                />
+                <PaceTable />
+                <ActivityComparison forms_get=forms_get form_states_get=form_states_get />
+                <MultisportPlanner />
+                <RowingCalculator />
                 <div style=format!(
                     "text-align: center; margin-top: 20px; font-size: 12px; color: {};",
                     colors::GREY,
@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::units::{Distance, Pace, Speed, TimeSpan};
+
+pub struct Preset {
+    pub name: &'static str,
+    pub swim_distance: Distance,
+    pub bike_distance: Distance,
+    pub run_distance: Distance,
+}
+
+pub const PRESETS: [Preset; 4] = [
+    Preset {
+        name: "Sprint",
+        swim_distance: Distance::meters(750),
+        bike_distance: Distance::meters(20000),
+        run_distance: Distance::meters(5000),
+    },
+    Preset {
+        name: "Olympic",
+        swim_distance: Distance::meters(1500),
+        bike_distance: Distance::meters(40000),
+        run_distance: Distance::meters(10000),
+    },
+    Preset {
+        name: "Half (70.3)",
+        swim_distance: Distance::meters(1900),
+        bike_distance: Distance::meters(90000),
+        run_distance: Distance::meters(21097),
+    },
+    Preset {
+        name: "Full (140.6)",
+        swim_distance: Distance::meters(3800),
+        bike_distance: Distance::meters(180000),
+        run_distance: Distance::meters(42195),
+    },
+];
+
+/// Time for swimming `distance` at `pace` per 100 m.
+pub fn swim_time(distance: Distance, pace: TimeSpan) -> Option<TimeSpan> {
+    (!distance.is_zero() && pace > TimeSpan::ZERO).then(|| {
+        TimeSpan::from_secs_f64(
+            distance.as_meters() as f64 / 100.0 * pace.as_duration().as_secs_f64(),
+        )
+    })
+}
+
+/// Time for riding `distance` at `speed`.
+pub fn bike_time(distance: Distance, speed: Speed) -> Option<TimeSpan> {
+    (!distance.is_zero() && speed.as_kmh() > 0.0).then(|| distance / speed)
+}
+
+/// Time for running `distance` at `pace`.
+pub fn run_time(distance: Distance, pace: Pace) -> Option<TimeSpan> {
+    (!distance.is_zero() && !pace.is_zero()).then(|| pace * distance)
+}
+
+/// Adds the cumulative time to every leg. The cumulative time is unknown
+/// from the first leg whose time is unknown onwards.
+pub fn with_cumulative(
+    legs: Vec<(&'static str, Option<TimeSpan>)>,
+) -> Vec<(&'static str, Option<TimeSpan>, Option<TimeSpan>)> {
+    let mut cumulative = Some(TimeSpan::ZERO);
+    legs.into_iter()
+        .map(|(name, time)| {
+            cumulative = cumulative.zip(time).map(|(total, time)| total + time);
+            (name, time, cumulative)
+        })
+        .collect()
+}
@@ -0,0 +1,217 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Reads paces the way runners type them, for example `4:30`, `4.30`,
+// `4'30"`, `430`, `4 min 30` or `4,5` for decimal minutes.
+
+use crate::units::Pace;
+use std::time::Duration;
+
+const FORMAT_HINT: &str = "use m:ss, for example 4:30";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Number(&'a str),
+    Word(&'a str),
+    Symbol(char),
+}
+
+fn tokenize(s: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c.is_ascii_digit() || c.is_alphabetic() {
+            let end = rest
+                .find(|next: char| {
+                    if c.is_ascii_digit() {
+                        !next.is_ascii_digit()
+                    } else {
+                        !next.is_alphabetic()
+                    }
+                })
+                .unwrap_or(rest.len());
+            tokens.push(if c.is_ascii_digit() {
+                Token::Number(&rest[..end])
+            } else {
+                Token::Word(&rest[..end])
+            });
+            rest = &rest[end..];
+        } else {
+            tokens.push(Token::Symbol(c));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    tokens
+}
+
+/// Removes a trailing `/km`, `per km` or `min/km`.
+fn strip_unit<'a>(mut tokens: &'a [Token<'a>]) -> &'a [Token<'a>] {
+    if let [rest @ .., Token::Symbol('/') | Token::Word("per"), Token::Word("km")] = tokens {
+        tokens = rest;
+    }
+    if let [rest @ .., Token::Number(_), Token::Word("min" | "mins" | "minutes")] = tokens {
+        if !rest.is_empty() {
+            tokens = &tokens[..tokens.len() - 1];
+        }
+    }
+    tokens
+}
+
+fn number(digits: &str) -> Result<u64, String> {
+    digits
+        .parse()
+        .map_err(|_| format!("{} is too large for a pace", digits))
+}
+
+fn whole_minutes(minutes: &str) -> Result<Duration, String> {
+    number(minutes)?
+        .checked_mul(60)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("{} is too large for a pace", minutes))
+}
+
+fn minutes_and_seconds(minutes: &str, seconds: &str) -> Result<Duration, String> {
+    let seconds = number(seconds)?;
+    if seconds >= 60 {
+        return Err(format!("Seconds must be less than 60, got {}", seconds));
+    }
+    whole_minutes(minutes)?
+        .checked_add(Duration::from_secs(seconds))
+        .ok_or_else(|| format!("{} is too large for a pace", minutes))
+}
+
+fn decimal_minutes(minutes: &str, fraction: &str) -> Result<Duration, String> {
+    let minutes: f64 = format!("{}.{}", minutes, fraction)
+        .parse()
+        .map_err(|_| format!("Could not read the pace, {}", FORMAT_HINT))?;
+    Ok(Duration::from_secs((minutes * 60.0).round() as u64))
+}
+
+/// Parses a pace per km, optionally followed by `/km`. Two digits after a
+/// decimal point are seconds (`4.30` is 4:30), `.5` or more than two are
+/// decimal minutes (`4.5` is 4:30). Inputs that fit neither, like `4.75`
+/// or `4.3`, are rejected as ambiguous.
+pub fn parse_pace(s: &str) -> Result<Pace, String> {
+    use Token::{Number, Symbol, Word};
+
+    let s = s
+        .trim()
+        .to_lowercase()
+        .replace("''", "\"")
+        .replace(['′', '’'], "'")
+        .replace(['″', '”'], "\"");
+    let tokens = tokenize(&s);
+    // Marks minutes and seconds the same way however they were written.
+    let mut tokens: Vec<Token> = strip_unit(&tokens)
+        .iter()
+        .map(|token| match token {
+            Symbol(':') | Word("m" | "min" | "mins" | "minutes") => Symbol('\''),
+            Word("s" | "sec" | "secs" | "seconds") => Symbol('"'),
+            &other => other,
+        })
+        .collect();
+    if let [Number(_), Symbol('\''), Number(_), Symbol('"')] = tokens[..] {
+        tokens.pop();
+    }
+    let duration = match tokens[..] {
+        [Number(digits)] => match digits.len() {
+            1 | 2 => whole_minutes(digits)?,
+            3 | 4 => {
+                let (minutes, seconds) = digits.split_at(digits.len() - 2);
+                minutes_and_seconds(minutes, seconds)?
+            }
+            _ => return Err(format!("{} has too many digits, {}", digits, FORMAT_HINT)),
+        },
+        [Number(minutes), Symbol('\'')] => whole_minutes(minutes)?,
+        [Number(minutes), Symbol('\''), Number(seconds)] => minutes_and_seconds(minutes, seconds)?,
+        [Number(minutes), Symbol(separator @ ('.' | ',')), Number(fraction)] => {
+            if fraction.len() > 2 || fraction == "5" {
+                decimal_minutes(minutes, fraction)?
+            } else if fraction.len() == 2 && number(fraction)? < 60 {
+                minutes_and_seconds(minutes, fraction)?
+            } else {
+                return Err(format!(
+                    "{}{}{} is ambiguous, write {} if you mean {}.{} minutes",
+                    minutes,
+                    separator,
+                    fraction,
+                    Pace::per_km(decimal_minutes(minutes, fraction)?),
+                    minutes,
+                    fraction,
+                ));
+            }
+        }
+        _ => return Err(format!("Could not read the pace, {}", FORMAT_HINT)),
+    };
+    Ok(Pace::per_km(duration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_common_notations() {
+        for (input, seconds) in [
+            ("4:30", 270),
+            ("4.30", 270),
+            ("4'30\"", 270),
+            ("4:30/km", 270),
+            ("4:30 min/km", 270),
+            ("430", 270),
+            ("4 min 30", 270),
+            ("4min30s", 270),
+            ("4,5", 270),
+            ("4.5", 270),
+            ("4", 240),
+            ("10:05 per km", 605),
+        ] {
+            assert_eq!(
+                parse_pace(input),
+                Ok(Pace::per_km(Duration::from_secs(seconds))),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_ambiguous_and_invalid_paces() {
+        for input in [
+            "4.75",
+            "4:75",
+            "475",
+            "4.3",
+            "4,75 min/km",
+            "12345",
+            "999999999999999999:00",
+            "999999999999999999'",
+            "abc",
+            "",
+        ] {
+            assert!(parse_pace(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::form_state::FormState;
+use crate::splits::split_times_with_stops;
+use crate::stops::stop_time_before;
+use crate::units::{Distance, TimeSpan};
+use std::fmt::Write;
+
+/// Common pace band widths in millimeters.
+pub const BAND_WIDTHS: [u32; 3] = [20, 25, 30];
+
+/// Closest marker distance, which keeps a marathon band at about 420 rows.
+pub const MIN_MARKER: Distance = Distance::meters(100);
+
+const MARGIN: f64 = 1.5;
+/// Band width per font size, chosen so that `Target h:mm:ss` fits.
+const WIDTH_PER_FONT_SIZE: f64 = 7.5;
+
+/// Formats a time as `mm:ss`, or as `h:mm:ss` from an hour on.
+fn format_time(time: TimeSpan) -> String {
+    if time.as_secs() >= 3600 {
+        time.format_hms()
+    } else {
+        time.to_string()
+    }
+}
+
+fn format_distance(distance: Distance) -> String {
+    let distance = distance.as_meters();
+    if distance.is_multiple_of(1000) {
+        format!("{} km", distance / 1000)
+    } else if distance > 1000 {
+        format!("{:.1} km", distance as f64 / 1000.0)
+    } else {
+        format!("{} m", distance)
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a pace band for the form with the cumulative time at every
+/// `marker` meters as SVG that is `width` millimeters wide.
+pub fn pace_band_svg(state: &FormState, width: u32, marker: Distance) -> String {
+    let width = width as f64;
+    let font_size = width / WIDTH_PER_FONT_SIZE;
+    let row_height = font_size * 1.6;
+    let baseline = font_size * 0.4;
+    let target = state.pace * state.distance + stop_time_before(&state.stops, state.distance);
+    let mut header = Vec::new();
+    if !state.label.is_empty() {
+        header.push(escape(&state.label));
+    }
+    header.push(format!("Target {}", format_time(target)));
+    header.push(format!("{} /km", state.pace));
+    let rows = split_times_with_stops(state.pace, state.distance, marker, &state.stops);
+    let height = 2.0 * MARGIN + (header.len() + rows.len()) as f64 * row_height + 1.0;
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.2}mm" height="{h:.2}mm" viewBox="0 0 {w:.2} {h:.2}" font-family="sans-serif" font-size="{f:.2}">"#,
+        w = width,
+        h = height,
+        f = font_size,
+    );
+    let _ = write!(
+        svg,
+        r#"<rect x="0.25" y="0.25" width="{:.2}" height="{:.2}" rx="2" fill="white" stroke="black" stroke-width="0.5"/>"#,
+        width - 0.5,
+        height - 0.5,
+    );
+    let mut y = MARGIN;
+    for line in &header {
+        y += row_height;
+        let _ = write!(
+            svg,
+            r#"<text x="{:.2}" y="{:.2}" text-anchor="middle" font-weight="bold">{}</text>"#,
+            width / 2.0,
+            y - baseline,
+            line,
+        );
+    }
+    let _ = write!(
+        svg,
+        r#"<line x1="{:.2}" y1="{y:.2}" x2="{:.2}" y2="{y:.2}" stroke="black" stroke-width="0.3"/>"#,
+        MARGIN,
+        width - MARGIN,
+        y = y + 0.5,
+    );
+    y += 1.0;
+    for (index, (distance, time)) in rows.into_iter().enumerate() {
+        if index % 2 == 1 {
+            let _ = write!(
+                svg,
+                r##"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="#e6e6e6"/>"##,
+                MARGIN,
+                y,
+                width - 2.0 * MARGIN,
+                row_height,
+            );
+        }
+        y += row_height;
+        let _ = write!(
+            svg,
+            r#"<text x="{:.2}" y="{ty:.2}">{}</text><text x="{:.2}" y="{ty:.2}" text-anchor="end">{}</text>"#,
+            MARGIN + 0.5,
+            format_distance(distance),
+            width - MARGIN - 0.5,
+            format_time(time),
+            ty = y - baseline,
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
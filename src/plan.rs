@@ -0,0 +1,106 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::form_state::FormState;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Version of the serialized plan format. Bump it when a change to
+/// `FormState` cannot be read from older data through `#[serde(default)]`
+/// and add a migration step to `Plan::from_json`.
+pub const PLAN_VERSION: u32 = 1;
+
+/// The forms of the calculator in the order they are shown. This is the
+/// format of local storage, share links and plan files. Fields of a form
+/// that are missing take their default value, see the README for the
+/// schema.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    pub version: u32,
+    pub forms: Vec<PlanForm>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlanForm {
+    pub id: usize,
+    pub state: FormState,
+}
+
+impl Plan {
+    pub fn new(forms: &[usize], states: &HashMap<usize, FormState>) -> Self {
+        Self {
+            version: PLAN_VERSION,
+            forms: forms
+                .iter()
+                .map(|&id| PlanForm {
+                    id,
+                    state: states.get(&id).cloned().unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Reads a plan of any known version, migrating it to the current one.
+    pub fn from_json(s: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(s).map_err(|err| format!("Invalid plan: {}", err))?;
+        Self::from_value(value)
+    }
+
+    /// Like [`Plan::from_json`] for an already parsed JSON value.
+    pub fn from_value(value: serde_json::Value) -> Result<Self, String> {
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| "Invalid plan: missing version".to_string())?;
+        let plan: Plan = match version {
+            1 => serde_json::from_value(value).map_err(|err| format!("Invalid plan: {}", err))?,
+            _ => return Err(format!("Unsupported plan version {}", version)),
+        };
+        // Forms are keyed by id, so every id must be used only once.
+        let mut ids = HashSet::new();
+        if let Some(form) = plan.forms.iter().find(|form| !ids.insert(form.id)) {
+            return Err(format!("Invalid plan: form id {} is used twice", form.id));
+        }
+        Ok(plan)
+    }
+
+    /// Returns the form ids in order and the state of every form.
+    pub fn into_forms(self) -> (Vec<usize>, HashMap<usize, FormState>) {
+        let forms = self.forms.iter().map(|form| form.id).collect();
+        let states = self
+            .forms
+            .into_iter()
+            .map(|form| (form.id, form.state))
+            .collect();
+        (forms, states)
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Splits and watts are converted with the Concept2 formula
+// watts = 2.80 / (split / 500)^3, where split is in seconds per 500 m.
+
+use crate::units::{Distance, Pace, TimeSpan};
+
+/// Standard erg test and race distances in meters.
+pub const ERG_DISTANCES: [Distance; 8] = [
+    Distance::meters(500),
+    Distance::meters(1000),
+    Distance::meters(2000),
+    Distance::meters(5000),
+    Distance::meters(6000),
+    Distance::meters(10000),
+    Distance::meters(21097),
+    Distance::meters(42195),
+];
+
+pub const SPLIT_DISTANCE: Distance = Distance::meters(500);
+
+const WATTS_FACTOR: f64 = 2.80;
+
+pub fn split_to_watts(split: TimeSpan) -> f64 {
+    let pace = split.as_duration().as_secs_f64() / SPLIT_DISTANCE.as_meters() as f64;
+    WATTS_FACTOR / pace.powi(3)
+}
+
+/// Split for `watts`, zero if the split is out of range.
+pub fn watts_to_split(watts: f64) -> TimeSpan {
+    let pace = (WATTS_FACTOR / watts).cbrt();
+    TimeSpan::from_secs_f64(pace * SPLIT_DISTANCE.as_meters() as f64)
+}
+
+/// Pace for a split per 500 m.
+pub fn split_to_pace(split: TimeSpan) -> Pace {
+    Pace::per_km(
+        split
+            .as_duration()
+            .saturating_mul((1000 / SPLIT_DISTANCE.as_meters()) as u32),
+    )
+}
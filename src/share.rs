@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::form_state::FormState;
+use crate::plan::{Plan, PLAN_VERSION};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::Value;
+
+const PLAN_PREFIX: &str = "plan=";
+
+const INVALID_LINK: &str = "The share link is incomplete or damaged.";
+const UNSUPPORTED_LINK: &str =
+    "The share link could not be opened. It may be from a different version of the pace calculator.";
+
+/// Removes all form fields that have their default value to keep links
+/// short. They are restored by `#[serde(default)]` on `FormState`.
+fn strip_defaults(plan: &mut Value) {
+    let Ok(Value::Object(defaults)) = serde_json::to_value(FormState::default()) else {
+        return;
+    };
+    let forms = plan.get_mut("forms").and_then(Value::as_array_mut);
+    for form in forms.into_iter().flatten() {
+        if let Some(Value::Object(state)) = form.get_mut("state") {
+            state.retain(|field, value| defaults.get(field) != Some(value));
+        }
+    }
+}
+
+/// Encodes a plan as URL fragment (without the leading `#`).
+pub fn encode_share_fragment(plan: &Plan) -> String {
+    let json = match serde_json::to_value(plan) {
+        Ok(mut value) => {
+            strip_defaults(&mut value);
+            value.to_string()
+        }
+        Err(_) => plan.to_json(),
+    };
+    format!("{}{}", PLAN_PREFIX, URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Decodes a URL fragment created by [`encode_share_fragment`]. Returns
+/// `None` if the fragment does not contain a plan at all.
+pub fn decode_share_fragment(fragment: &str) -> Option<Result<Plan, String>> {
+    let encoded = fragment.strip_prefix(PLAN_PREFIX)?;
+    let plan = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        .ok_or_else(|| INVALID_LINK.to_string())
+        .and_then(|value| match value.get("version").and_then(Value::as_u64) {
+            Some(version) if version > u64::from(PLAN_VERSION) => Err(UNSUPPORTED_LINK.to_string()),
+            _ => Plan::from_value(value).map_err(|_| INVALID_LINK.to_string()),
+        });
+    Some(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Distance, Pace};
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn fragment(json: &str) -> String {
+        format!("{}{}", PLAN_PREFIX, URL_SAFE_NO_PAD.encode(json))
+    }
+
+    #[test]
+    fn round_trips_plan_without_default_fields() {
+        let states = HashMap::from([
+            (
+                3,
+                FormState {
+                    pace: Pace::per_km(Duration::from_secs(270)),
+                    distance: Distance::meters(10000),
+                    ..FormState::default()
+                },
+            ),
+            (7, FormState::default()),
+        ]);
+        let plan = Plan::new(&[7, 3], &states);
+
+        let fragment = encode_share_fragment(&plan);
+        let json = URL_SAFE_NO_PAD
+            .decode(fragment.strip_prefix(PLAN_PREFIX).unwrap())
+            .unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.contains("\"distance\":10000"));
+        assert!(!json.contains("label"));
+        assert!(!json.contains("show_splits"));
+        assert_eq!(decode_share_fragment(&fragment), Some(Ok(plan)));
+    }
+
+    #[test]
+    fn rejects_damaged_links() {
+        let link = encode_share_fragment(&Plan::new(&[0], &HashMap::new()));
+        for damaged in [
+            format!("{}!!", &link[..link.len() - 2]),
+            link[..link.len() - 5].to_string(),
+            fragment("not json"),
+            fragment(r#"{"version":1,"forms":"x"}"#),
+        ] {
+            assert_eq!(
+                decode_share_fragment(&damaged),
+                Some(Err(INVALID_LINK.to_string())),
+                "{}",
+                damaged
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_links_from_newer_versions() {
+        let json = format!(r#"{{"version":{},"forms":[]}}"#, PLAN_VERSION + 1);
+        assert_eq!(
+            decode_share_fragment(&fragment(&json)),
+            Some(Err(UNSUPPORTED_LINK.to_string()))
+        );
+    }
+
+    #[test]
+    fn ignores_fragments_without_plan() {
+        assert_eq!(decode_share_fragment(""), None);
+        assert_eq!(decode_share_fragment("section-2"), None);
+    }
+}
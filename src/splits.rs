@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::stops::{stop_time_before, Stop};
+use crate::units::{Distance, Pace, TimeSpan};
+
+/// Returns the distance and the elapsed time at every split up to
+/// `distance` when running at `pace`. If `distance` is not a multiple of
+/// `splits`, the last split is shorter.
+pub fn split_times(pace: Pace, distance: Distance, splits: Distance) -> Vec<(Distance, TimeSpan)> {
+    if pace.is_zero() || distance.is_zero() || splits.is_zero() {
+        return Vec::new();
+    }
+    let mut times: Vec<(Distance, TimeSpan)> = (splits.as_meters()..=distance.as_meters())
+        .step_by(splits.as_meters())
+        .map(|i| (Distance::meters(i), pace * Distance::meters(i)))
+        .collect();
+    if !distance.as_meters().is_multiple_of(splits.as_meters()) {
+        times.push((distance, pace * distance));
+    }
+    times
+}
+
+/// Like [`split_times`], but includes the time of the stops before each
+/// split.
+pub fn split_times_with_stops(
+    pace: Pace,
+    distance: Distance,
+    splits: Distance,
+    stops: &[Stop],
+) -> Vec<(Distance, TimeSpan)> {
+    split_times(pace, distance, splits)
+        .into_iter()
+        .map(|(i, time)| (i, time + stop_time_before(stops, i)))
+        .collect()
+}
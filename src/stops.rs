@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::units::{Distance, TimeSpan};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stop {
+    pub distance: Distance,
+    pub duration: TimeSpan,
+}
+
+/// Returns the total time spent in stops before reaching `distance`.
+/// A stop at `distance` itself is taken after arriving there.
+pub fn stop_time_before(stops: &[Stop], distance: Distance) -> TimeSpan {
+    stops
+        .iter()
+        .filter(|stop| stop.distance < distance)
+        .map(|stop| stop.duration)
+        .sum()
+}
+
+fn parse_stop(line: &str) -> Result<Stop, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() != 2 {
+        return Err("Expected distance, duration".to_string());
+    }
+    let distance = fields[0].parse::<Distance>()?;
+    let duration = fields[1].parse::<TimeSpan>()?;
+    Ok(Stop { distance, duration })
+}
+
+/// Parses planned stops with one stop per line, given as
+/// `distance (m), duration (mm:ss)`. Empty lines are ignored.
+pub fn parse_stops(s: &str) -> Result<Vec<Stop>, String> {
+    let mut stops = Vec::new();
+    for (index, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let stop = parse_stop(line).map_err(|err| format!("Line {}: {}", index + 1, err))?;
+        stops.push(stop);
+    }
+    stops.sort_by_key(|stop| stop.distance);
+    Ok(stops)
+}
+
+/// Formats stops in the format accepted by [`parse_stops`].
+pub fn format_stops(stops: &[Stop]) -> String {
+    stops
+        .iter()
+        .map(|stop| format!("{}, {}", stop.distance.as_meters(), stop.duration))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
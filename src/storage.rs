@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::library::Library;
+use crate::plan::Plan;
+use leptos::prelude::*;
+
+const FORMS_KEY: &str = "pacalc.forms";
+const LIBRARY_KEY: &str = "pacalc.library";
+const DRAFT_KEY: &str = "pacalc.draft";
+
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Restores the forms saved by [`save_forms`], if any.
+pub fn load_forms() -> Option<Plan> {
+    let json = local_storage()?.get_item(FORMS_KEY).ok().flatten()?;
+    Plan::from_json(&json).ok()
+}
+
+pub fn save_forms(plan: &Plan) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(FORMS_KEY, &plan.to_json());
+    }
+}
+
+/// Reads the library of named plans, which is empty if nothing was saved
+/// yet or the stored data cannot be read.
+pub fn load_library() -> Library {
+    local_storage()
+        .and_then(|storage| storage.get_item(LIBRARY_KEY).ok().flatten())
+        .and_then(|json| Library::from_json(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_library(library: &Library) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(LIBRARY_KEY, &library.to_json());
+    }
+}
+
+/// Restores the draft saved by [`save_draft`], if any.
+pub fn load_draft() -> Option<Plan> {
+    let json = local_storage()?.get_item(DRAFT_KEY).ok().flatten()?;
+    Plan::from_json(&json).ok()
+}
+
+/// Keeps the working set of forms before another plan replaces it.
+pub fn save_draft(plan: &Plan) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(DRAFT_KEY, &plan.to_json());
+    }
+}
+
+/// Drops the draft once it is restored or discarded.
+pub fn remove_draft() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(DRAFT_KEY);
+    }
+}
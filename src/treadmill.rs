@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Speeds are converted via the ACSM running equation, where the oxygen
+// cost per minute is 0.2 * v + 0.9 * v * grade (v in m/min). Running
+// outdoors on the flat costs about as much as running on a treadmill at
+// 1 % incline because of air resistance (Jones & Doust, 1996).
+
+use crate::units::{Pace, Speed};
+
+/// Inclines in percent offered for conversion.
+pub const INCLINES: [f64; 7] = [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0];
+
+const HORIZONTAL_COST: f64 = 0.2;
+const VERTICAL_COST: f64 = 0.9;
+const OUTDOOR_EQUIVALENT_INCLINE: f64 = 1.0;
+
+fn cost_factor(incline_percent: f64) -> f64 {
+    HORIZONTAL_COST + VERTICAL_COST * incline_percent / 100.0
+}
+
+/// Returns the treadmill speed at the given incline that takes the same
+/// effort as running outdoors at `pace`.
+pub fn treadmill_speed(pace: Pace, incline_percent: f64) -> Speed {
+    Speed::from(pace) * (cost_factor(OUTDOOR_EQUIVALENT_INCLINE) / cost_factor(incline_percent))
+}
+
+/// Returns the outdoor pace that takes the same effort as running at
+/// `speed` on a treadmill at the given incline.
+pub fn outdoor_pace(speed: Speed, incline_percent: f64) -> Pace {
+    Pace::from(speed * (cost_factor(incline_percent) / cost_factor(OUTDOOR_EQUIVALENT_INCLINE)))
+}
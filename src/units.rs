@@ -0,0 +1,462 @@
+// Copyright (c) 2025 Florian Lorenzen
+
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation
+// files (the "Software"), to deal in the Software without
+// restriction, including without limitation the rights to use,
+// copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following
+// conditions:
+
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES
+// OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT
+// HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
+// WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+
+// Domain types for the quantities of a plan. They serialize like the
+// plain values they wrap, so the plan format does not change.
+
+use crate::distance::parse_distance;
+use crate::duration::{format_duration, parse_duration};
+use crate::pace::parse_pace;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Sub};
+use std::str::FromStr;
+use std::time::Duration;
+
+const METERS_PER_KM: f64 = 1000.0;
+const KM_PER_MILE: f64 = 1.609344;
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Duration of `seconds`, zero if they are negative, not a number or out
+/// of range, e.g. for a speed close to zero.
+fn duration_or_zero(seconds: f64) -> Duration {
+    Duration::try_from_secs_f64(seconds).unwrap_or(Duration::ZERO)
+}
+
+/// Time per km.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pace(Duration);
+
+/// Distance in whole meters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Distance(usize);
+
+/// Speed in km/h.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Speed(f64);
+
+/// Elapsed time, e.g. the time to cover a distance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TimeSpan(Duration);
+
+/// Time of day since midnight, e.g. a start time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TimeOfDay(Duration);
+
+impl Pace {
+    pub const ZERO: Pace = Pace(Duration::ZERO);
+
+    pub const fn per_km(time: Duration) -> Self {
+        Pace(time)
+    }
+
+    pub fn time_per_km(self) -> Duration {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn saturating_sub(self, time: Duration) -> Self {
+        Pace(self.0.saturating_sub(time))
+    }
+}
+
+/// Slower pace, saturating at the longest duration.
+impl Add<Duration> for Pace {
+    type Output = Pace;
+
+    fn add(self, time: Duration) -> Pace {
+        Pace(self.0.saturating_add(time))
+    }
+}
+
+impl Distance {
+    pub const ZERO: Distance = Distance(0);
+
+    pub const fn meters(meters: usize) -> Self {
+        Distance(meters)
+    }
+
+    pub fn as_meters(self) -> usize {
+        self.0
+    }
+
+    pub fn as_km(self) -> f64 {
+        self.0 as f64 / METERS_PER_KM
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Add for Distance {
+    type Output = Distance;
+
+    fn add(self, other: Distance) -> Distance {
+        Distance(self.0.saturating_add(other.0))
+    }
+}
+
+/// Difference, zero if `other` is longer.
+impl Sub for Distance {
+    type Output = Distance;
+
+    fn sub(self, other: Distance) -> Distance {
+        Distance(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Speed {
+    pub const fn kmh(speed: f64) -> Self {
+        Speed(speed)
+    }
+
+    pub fn as_kmh(self) -> f64 {
+        self.0
+    }
+
+    pub fn as_mph(self) -> f64 {
+        self.0 / KM_PER_MILE
+    }
+}
+
+impl Mul<f64> for Speed {
+    type Output = Speed;
+
+    fn mul(self, factor: f64) -> Speed {
+        Speed(self.0 * factor)
+    }
+}
+
+impl TimeSpan {
+    pub const ZERO: TimeSpan = TimeSpan(Duration::ZERO);
+
+    pub const fn from_secs(seconds: u64) -> Self {
+        TimeSpan(Duration::from_secs(seconds))
+    }
+
+    /// Time span of `seconds`, zero if they are not a valid duration.
+    pub fn from_secs_f64(seconds: f64) -> Self {
+        TimeSpan(duration_or_zero(seconds))
+    }
+
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+
+    pub fn as_secs(self) -> u64 {
+        self.0.as_secs()
+    }
+
+    /// Formats the time span as `h:mm:ss`, which spreadsheets also read
+    /// as a time.
+    pub fn format_hms(self) -> String {
+        let seconds = self.0.as_secs();
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    }
+}
+
+impl From<Duration> for TimeSpan {
+    fn from(time: Duration) -> Self {
+        TimeSpan(time)
+    }
+}
+
+impl Add for TimeSpan {
+    type Output = TimeSpan;
+
+    fn add(self, other: TimeSpan) -> TimeSpan {
+        TimeSpan(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for TimeSpan {
+    fn add_assign(&mut self, other: TimeSpan) {
+        self.0 = self.0.saturating_add(other.0);
+    }
+}
+
+/// Difference, zero if `other` is longer.
+impl Sub for TimeSpan {
+    type Output = TimeSpan;
+
+    fn sub(self, other: TimeSpan) -> TimeSpan {
+        TimeSpan(self.0.saturating_sub(other.0))
+    }
+}
+
+impl TimeOfDay {
+    pub const fn since_midnight(time: Duration) -> Self {
+        TimeOfDay(time)
+    }
+
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl Sum for TimeSpan {
+    fn sum<I: Iterator<Item = TimeSpan>>(iter: I) -> TimeSpan {
+        iter.fold(TimeSpan::ZERO, Add::add)
+    }
+}
+
+/// Time to cover a distance at a pace, zero if it is out of range.
+impl Mul<Distance> for Pace {
+    type Output = TimeSpan;
+
+    fn mul(self, distance: Distance) -> TimeSpan {
+        TimeSpan(duration_or_zero(self.0.as_secs_f64() * distance.as_km()))
+    }
+}
+
+impl Mul<Pace> for Distance {
+    type Output = TimeSpan;
+
+    fn mul(self, pace: Pace) -> TimeSpan {
+        pace * self
+    }
+}
+
+/// Pace of covering a distance in a time span, zero for no distance.
+impl Div<Distance> for TimeSpan {
+    type Output = Pace;
+
+    fn div(self, distance: Distance) -> Pace {
+        if distance.is_zero() {
+            Pace::ZERO
+        } else {
+            Pace(duration_or_zero(self.0.as_secs_f64() / distance.as_km()))
+        }
+    }
+}
+
+/// Distance covered in a time span at a pace, zero for a zero pace.
+impl Div<Pace> for TimeSpan {
+    type Output = Distance;
+
+    fn div(self, pace: Pace) -> Distance {
+        if pace.is_zero() {
+            Distance::ZERO
+        } else {
+            let km = self.0.as_secs_f64() / pace.0.as_secs_f64();
+            Distance((km * METERS_PER_KM).round() as usize)
+        }
+    }
+}
+
+/// Time to cover a distance at a speed, zero if the speed is not positive.
+impl Div<Speed> for Distance {
+    type Output = TimeSpan;
+
+    fn div(self, speed: Speed) -> TimeSpan {
+        if speed.0 > 0.0 {
+            TimeSpan::from_secs_f64(self.as_km() / speed.0 * SECONDS_PER_HOUR)
+        } else {
+            TimeSpan::ZERO
+        }
+    }
+}
+
+/// Speed of a pace, zero for a zero pace.
+impl From<Pace> for Speed {
+    fn from(pace: Pace) -> Self {
+        if pace.is_zero() {
+            Speed(0.0)
+        } else {
+            Speed(SECONDS_PER_HOUR / pace.0.as_secs_f64())
+        }
+    }
+}
+
+/// Pace of a speed, zero if the speed is not positive.
+impl From<Speed> for Pace {
+    fn from(speed: Speed) -> Self {
+        if speed.0 > 0.0 {
+            Pace(duration_or_zero(SECONDS_PER_HOUR / speed.0))
+        } else {
+            Pace::ZERO
+        }
+    }
+}
+
+impl fmt::Display for Pace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format_duration(self.0))
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}m", self.0)
+    }
+}
+
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.1} km/h", self.0)
+    }
+}
+
+impl fmt::Display for TimeSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format_duration(self.0))
+    }
+}
+
+/// Shows the time of day as `hh:mm:ss`.
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:0>8}", TimeSpan(self.0).format_hms())
+    }
+}
+
+/// Parses a pace as runners type it, see [`parse_pace`].
+impl FromStr for Pace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        parse_pace(s)
+    }
+}
+
+/// Parses a distance in meters or with a unit, see [`parse_distance`].
+impl FromStr for Distance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        parse_distance(s)
+    }
+}
+
+/// Parses a speed in km/h with an optional `km/h` suffix.
+impl FromStr for Speed {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        match s
+            .strip_suffix("km/h")
+            .unwrap_or(s)
+            .trim_end()
+            .parse::<f64>()
+        {
+            Ok(speed) if speed.is_finite() && speed >= 0.0 => Ok(Speed(speed)),
+            _ => Err("Speed must be a positive number".to_string()),
+        }
+    }
+}
+
+/// Parses a time span given as `mm:ss`.
+impl FromStr for TimeSpan {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        parse_duration(s).map(TimeSpan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pace(seconds: u64) -> Pace {
+        Pace::per_km(Duration::from_secs(seconds))
+    }
+
+    fn time(seconds: u64) -> TimeSpan {
+        TimeSpan::from(Duration::from_secs(seconds))
+    }
+
+    #[test]
+    fn converts_between_pace_distance_and_time() {
+        assert_eq!(pace(300) * Distance::meters(10000), time(3000));
+        assert_eq!(Distance::meters(500) * pace(300), time(150));
+        assert_eq!(time(3000) / Distance::meters(10000), pace(300));
+        assert_eq!(time(3000) / pace(300), Distance::meters(10000));
+        assert_eq!(Distance::meters(30000) / Speed::kmh(30.0), time(3600));
+        assert_eq!(Pace::from(Speed::kmh(12.0)), pace(300));
+        assert_eq!(Speed::from(pace(300)), Speed::kmh(12.0));
+    }
+
+    #[test]
+    fn treats_zero_as_unknown() {
+        assert_eq!(time(3000) / Distance::ZERO, Pace::ZERO);
+        assert_eq!(time(3000) / Pace::ZERO, Distance::ZERO);
+        assert_eq!(Distance::meters(1000) / Speed::kmh(0.0), TimeSpan::ZERO);
+        assert_eq!(Pace::from(Speed::kmh(-1.0)), Pace::ZERO);
+        assert_eq!(Speed::from(Pace::ZERO), Speed::kmh(0.0));
+    }
+
+    #[test]
+    fn saturates_instead_of_overflowing() {
+        let longest = Pace::per_km(Duration::MAX);
+        assert_eq!(
+            pace(300_000_000_000_000) * Distance::meters(4_000_000_000),
+            TimeSpan::ZERO
+        );
+        assert_eq!(longest + Duration::from_secs(1), longest);
+        assert_eq!(
+            TimeSpan::from(Duration::MAX) + time(1),
+            TimeSpan::from(Duration::MAX)
+        );
+        assert_eq!(time(1) - time(2), TimeSpan::ZERO);
+        assert_eq!(Distance::meters(1) - Distance::meters(2), Distance::ZERO);
+        assert_eq!(
+            Distance::meters(1000) / Speed::kmh(f64::MIN_POSITIVE),
+            TimeSpan::ZERO
+        );
+        assert_eq!(TimeSpan::from_secs_f64(f64::NAN), TimeSpan::ZERO);
+    }
+
+    #[test]
+    fn formats_and_parses() {
+        assert_eq!(pace(270).to_string(), "04:30");
+        assert_eq!(Distance::meters(400).to_string(), "400m");
+        assert_eq!(Speed::kmh(12.0).to_string(), "12.0 km/h");
+        assert_eq!(time(3909).format_hms(), "1:05:09");
+        assert_eq!(time(36_000).format_hms(), "10:00:00");
+        assert_eq!(
+            TimeOfDay::since_midnight(Duration::from_secs(25_200)).to_string(),
+            "07:00:00"
+        );
+        assert_eq!("30 km/h".parse::<Speed>(), Ok(Speed::kmh(30.0)));
+        assert!("-1".parse::<Speed>().is_err());
+        assert_eq!("4:30".parse::<Pace>(), Ok(pace(270)));
+        assert_eq!("10k".parse::<Distance>(), Ok(Distance::meters(10000)));
+    }
+}